@@ -1,6 +1,20 @@
+use node_warden::NodeWarden;
 use scrypto::prelude::*;
+use strum::IntoEnumIterator;
+use validator_proxy::{ValidatorProxy, ValidatorProxyFunctions};
 
-#[derive(Display, ScryptoSbor, EnumString)]
+#[derive(
+    Display,
+    ScryptoSbor,
+    ManifestSbor,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    EnumString,
+    strum_macros::EnumIter,
+)]
 #[strum(serialize_all = "snake_case")]
 pub enum PermissionType {
     StakeAsOwner,
@@ -18,13 +32,303 @@ pub enum PermissionType {
     RemoveMetadata,
     LockMetadata,
 
+    GetRole,
+    SetRole,
+
+    StakeFromTreasury,
+    CompoundOwnerRewards,
+    Unstake,
+    ClaimXrd,
+
+    CallValidatorMethod,
+    CallComponentMethod,
+
+    IssueAccessKeys,
+
     CreateValidatorOwnerBadgeProof,
 }
 
+impl PermissionType {
+    /// Whether a proof of the validator owner badge can do the same as this permission.
+    /// Holders of `create_validator_owner_badge_proof` implicitly have these permissions.
+    /// Funds in custody of NodeWarden and the issuance of access keys are not reachable
+    /// with a validator owner badge proof.
+    pub fn is_implied_by_validator_owner_badge_proof(&self) -> bool {
+        !matches!(
+            self,
+            PermissionType::StakeFromTreasury
+                | PermissionType::CompoundOwnerRewards
+                | PermissionType::Unstake
+                | PermissionType::ClaimXrd
+                | PermissionType::IssueAccessKeys
+        )
+    }
+
+    /// Whether the checks of this permission depend on the call, like a metadata
+    /// schema or treasury allowance. These can only be evaluated for a full
+    /// [`ProposedAction`].
+    pub fn requires_proposed_action(&self) -> bool {
+        matches!(
+            self,
+            PermissionType::SetMetadata
+                | PermissionType::GetRole
+                | PermissionType::SetRole
+                | PermissionType::StakeFromTreasury
+                | PermissionType::CallValidatorMethod
+                | PermissionType::CallComponentMethod
+        )
+    }
+
+    /// Whether this permission acts on a validator. Issuing access keys does not,
+    /// so it is not tied to a managed validator or the validator scope.
+    pub fn acts_on_validator(&self) -> bool {
+        !matches!(self, PermissionType::IssueAccessKeys)
+    }
+}
+
+/// The reasons a NodeWarden call can fail. Every failure panics with the
+/// `Display` form of one of these: the name of the variant, followed by a
+/// description. The variant names are stable, so they can be matched on.
+/// Addresses are formatted as hex, so errors can also be displayed off-ledger.
+#[derive(ScryptoSbor, Clone, PartialEq, Eq, Debug)]
+pub enum NodeWardenError {
+    /// The proof is neither of the NodeWarden owner badge nor of an access key badge
+    WrongBadgeResource,
+    /// The proof must be of an access key badge
+    NotAnAccessKeyBadge,
+    /// The access key badge does not have the permission
+    PermissionDenied(PermissionType),
+    /// The access key badge is not scoped to the validator
+    NotScopedToValidator(ComponentAddress),
+    /// The access key badge has been reported compromised
+    AccessKeyCompromised,
+    /// This component does not hold the owner badge of the validator
+    NoValidatorOwnerBadge(ComponentAddress),
+    /// The owner badge of the validator is already deposited
+    ValidatorOwnerBadgeAlreadyDeposited(ComponentAddress),
+    /// The deposited bucket is not of validator owner badges
+    NotAValidatorOwnerBadge,
+    /// The deposited bucket of validator owner badges is empty
+    NoValidatorOwnerBadgeDeposited,
+    /// The string does not name a permission of [`AccessKeyPermissions`]
+    InvalidPermission(String),
+    /// The deposited bucket does not contain stake units of the validator
+    NotStakeUnitsOfValidator(ComponentAddress),
+    /// A treasury allowance can not be negative
+    NegativeAllowance,
+    /// The amount exceeds the treasury allowance of the access key badge
+    TreasuryAllowanceExceeded,
+    /// The access key badge has no issuance quota left
+    IssuanceQuotaExceeded,
+    /// Issued access key badges can not have more permissions than their issuer
+    IssuedPermissionsExceedIssuer,
+    /// Issued access key badges can not have a wider validator scope than their issuer
+    IssuedValidatorScopeExceedsIssuer,
+    /// The access key badge was not issued by the given access key badge
+    NotIssuedByAccessKey,
+    /// There is no pending move request for the access key badge
+    NoPendingMoveRequest,
+    /// The caller is not a validator proxy of this component
+    NotTheValidatorProxy(ComponentAddress),
+    /// The role of the validator is not delegable
+    RoleNotDelegable,
+    /// The validator method is not on the allowlist of the access key badge
+    ValidatorMethodNotAllowed(String),
+    /// The component method is not on the allowlist of the access key badge
+    ComponentMethodNotAllowed(String),
+    /// The arguments of a raw method call are not a tuple
+    ArgumentsNotATuple,
+    /// The metadata value has the wrong type for its field
+    MetadataWrongType(String),
+    /// The metadata value has too many elements for its field
+    MetadataTooManyElements(String),
+    /// The metadata value is too long for its field
+    MetadataTooLong(String),
+    /// The metadata value has an origin that is not allowed for its field
+    MetadataOriginNotAllowed(String),
+    /// The owner has not accepted a migration from the calling component
+    MigrationNotAccepted,
+    /// Access key badges of another component are already honoured
+    OtherAccessKeysHonoured,
+    /// The access key badge has no holder to deliver its re-minted badge to
+    UnknownAccessKeyHolder(NonFungibleLocalId),
+    /// The component is not a validator with a stake unit resource
+    NoStakeUnitResource(ComponentAddress),
+    /// The component is not a validator with a claim NFT resource
+    NoClaimNftResource(ComponentAddress),
+    /// The checks of the permission depend on the call, so the full action must be proposed
+    ProposedActionRequired(PermissionType),
+}
+
+impl fmt::Display for NodeWardenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeWardenError::WrongBadgeResource => write!(
+                f,
+                "WrongBadgeResource: The proof must be of the NodeWarden owner badge or an access key badge"
+            ),
+            NodeWardenError::NotAnAccessKeyBadge => write!(
+                f,
+                "NotAnAccessKeyBadge: The proof must be of an access key badge"
+            ),
+            NodeWardenError::PermissionDenied(permission_type) => write!(
+                f,
+                "PermissionDenied: Access key badge does not have permission for: {}",
+                permission_type
+            ),
+            NodeWardenError::NotScopedToValidator(validator_address) => write!(
+                f,
+                "NotScopedToValidator: Access key badge is not scoped to validator: {}",
+                validator_address.to_hex()
+            ),
+            NodeWardenError::AccessKeyCompromised => write!(
+                f,
+                "AccessKeyCompromised: Access key badge has been reported compromised"
+            ),
+            NodeWardenError::NoValidatorOwnerBadge(validator_address) => write!(
+                f,
+                "NoValidatorOwnerBadge: This component does not hold the owner badge of the given validator: {}",
+                validator_address.to_hex()
+            ),
+            NodeWardenError::ValidatorOwnerBadgeAlreadyDeposited(
+                validator_address,
+            ) => write!(
+                f,
+                "ValidatorOwnerBadgeAlreadyDeposited: The owner badge of this validator is already deposited: {}",
+                validator_address.to_hex()
+            ),
+            NodeWardenError::NotAValidatorOwnerBadge => write!(
+                f,
+                "NotAValidatorOwnerBadge: The deposited bucket must be a validator owner badge"
+            ),
+            NodeWardenError::NoValidatorOwnerBadgeDeposited => write!(
+                f,
+                "NoValidatorOwnerBadgeDeposited: The deposited bucket must contain at least one validator owner badge"
+            ),
+            NodeWardenError::InvalidPermission(permission) => write!(
+                f,
+                "InvalidPermission: Invalid permission type: {}",
+                permission
+            ),
+            NodeWardenError::NotStakeUnitsOfValidator(validator_address) => write!(
+                f,
+                "NotStakeUnitsOfValidator: The deposited bucket must contain stake units of the given validator: {}",
+                validator_address.to_hex()
+            ),
+            NodeWardenError::NegativeAllowance => write!(
+                f,
+                "NegativeAllowance: The allowance can not be negative"
+            ),
+            NodeWardenError::TreasuryAllowanceExceeded => write!(
+                f,
+                "TreasuryAllowanceExceeded: Access key badge treasury allowance exceeded"
+            ),
+            NodeWardenError::IssuanceQuotaExceeded => write!(
+                f,
+                "IssuanceQuotaExceeded: Access key badge issuance quota exceeded"
+            ),
+            NodeWardenError::IssuedPermissionsExceedIssuer => write!(
+                f,
+                "IssuedPermissionsExceedIssuer: Issued access key badge can not have more permissions than its issuer"
+            ),
+            NodeWardenError::IssuedValidatorScopeExceedsIssuer => write!(
+                f,
+                "IssuedValidatorScopeExceedsIssuer: Issued access key badge can not have a wider validator scope than its issuer"
+            ),
+            NodeWardenError::NotIssuedByAccessKey => write!(
+                f,
+                "NotIssuedByAccessKey: Access key badge was not issued by this access key badge"
+            ),
+            NodeWardenError::NoPendingMoveRequest => write!(
+                f,
+                "NoPendingMoveRequest: There is no pending move request for this access key badge"
+            ),
+            NodeWardenError::NotTheValidatorProxy(validator_proxy) => write!(
+                f,
+                "NotTheValidatorProxy: The caller is not a validator proxy of this component: {}",
+                validator_proxy.to_hex()
+            ),
+            NodeWardenError::RoleNotDelegable => write!(
+                f,
+                "RoleNotDelegable: This role of the validator is not delegable"
+            ),
+            NodeWardenError::ValidatorMethodNotAllowed(method_name) => write!(
+                f,
+                "ValidatorMethodNotAllowed: Access key badge is not allowed to call validator method: {}",
+                method_name
+            ),
+            NodeWardenError::ComponentMethodNotAllowed(method_name) => write!(
+                f,
+                "ComponentMethodNotAllowed: Access key badge is not allowed to call component method: {}",
+                method_name
+            ),
+            NodeWardenError::ArgumentsNotATuple => write!(
+                f,
+                "ArgumentsNotATuple: The method arguments must be a tuple"
+            ),
+            NodeWardenError::MetadataWrongType(name) => write!(
+                f,
+                "MetadataWrongType: Metadata value has the wrong type for field: {}",
+                name
+            ),
+            NodeWardenError::MetadataTooManyElements(name) => write!(
+                f,
+                "MetadataTooManyElements: Metadata value has too many elements for field: {}",
+                name
+            ),
+            NodeWardenError::MetadataTooLong(name) => write!(
+                f,
+                "MetadataTooLong: Metadata value is too long for field: {}",
+                name
+            ),
+            NodeWardenError::MetadataOriginNotAllowed(name) => write!(
+                f,
+                "MetadataOriginNotAllowed: Metadata value has an origin that is not allowed for field: {}",
+                name
+            ),
+            NodeWardenError::MigrationNotAccepted => write!(
+                f,
+                "MigrationNotAccepted: The owner has not accepted a migration from the calling component"
+            ),
+            NodeWardenError::OtherAccessKeysHonoured => write!(
+                f,
+                "OtherAccessKeysHonoured: Access key badges of another component are already honoured"
+            ),
+            NodeWardenError::UnknownAccessKeyHolder(local_id) => write!(
+                f,
+                "UnknownAccessKeyHolder: Access key badge has no holder to deliver it to: {}",
+                local_id
+            ),
+            NodeWardenError::NoStakeUnitResource(validator_address) => write!(
+                f,
+                "NoStakeUnitResource: The component is not a validator with a stake unit resource: {}",
+                validator_address.to_hex()
+            ),
+            NodeWardenError::NoClaimNftResource(validator_address) => write!(
+                f,
+                "NoClaimNftResource: The component is not a validator with a claim NFT resource: {}",
+                validator_address.to_hex()
+            ),
+            NodeWardenError::ProposedActionRequired(permission_type) => write!(
+                f,
+                "ProposedActionRequired: The checks of this permission depend on the call, propose the full action: {}",
+                permission_type
+            ),
+        }
+    }
+}
+
+impl NodeWardenError {
+    /// Panics with the stable message of this error.
+    pub fn raise(&self) -> ! {
+        panic!("{}", self)
+    }
+}
+
 /// It is actually nice to have this as a struct with bools here, because it is easy
 /// to inspect in the explorer. I thought about making it something simple like a u16
 /// and doing byte level stuff but ultimately this is easier to inspect.
-#[derive(ScryptoSbor, ManifestSbor)]
+#[derive(ScryptoSbor, ManifestSbor, Default)]
 pub struct AccessKeyPermissions {
     /// These correspond to the validator component's interface
     pub stake_as_owner: bool,
@@ -43,31 +347,27 @@ pub struct AccessKeyPermissions {
     pub remove_metadata: bool,
     pub lock_metadata: bool,
 
+    // Some permissions for managing the delegable roles of the validator component
+    pub get_role: bool,
+    pub set_role: bool,
+
+    // Some permissions for using funds held in custody by NodeWarden
+    pub stake_from_treasury: bool,
+    pub compound_owner_rewards: bool,
+    pub unstake: bool,
+    pub claim_xrd: bool,
+
+    // Permissions for calling methods on the allowlists of the access key
+    pub call_validator_method: bool,
+    pub call_component_method: bool,
+
+    // A permission for issuing attenuated access keys to others
+    pub issue_access_keys: bool,
+
     // An extra permission that is quite powerful, and can do all of the above
     pub create_validator_owner_badge_proof: bool,
 }
 
-impl Default for AccessKeyPermissions {
-    fn default() -> Self {
-        Self {
-            stake_as_owner: false,
-            register: false,
-            unregister: false,
-            update_key: false,
-            update_fee: false,
-            lock_owner_stake_units: false,
-            start_unlock_owner_stake_units: false,
-            finish_unlock_owner_stake_units: false,
-            update_accept_delegated_stake: false,
-            signal_protocol_update_readiness: false,
-            set_metadata: false,
-            remove_metadata: false,
-            lock_metadata: false,
-            create_validator_owner_badge_proof: false,
-        }
-    }
-}
-
 impl AccessKeyPermissions {
     pub fn is_allowed(&self, permission: &PermissionType) -> bool {
         match permission {
@@ -92,6 +392,15 @@ impl AccessKeyPermissions {
             PermissionType::SetMetadata => self.set_metadata,
             PermissionType::RemoveMetadata => self.remove_metadata,
             PermissionType::LockMetadata => self.lock_metadata,
+            PermissionType::GetRole => self.get_role,
+            PermissionType::SetRole => self.set_role,
+            PermissionType::StakeFromTreasury => self.stake_from_treasury,
+            PermissionType::CompoundOwnerRewards => self.compound_owner_rewards,
+            PermissionType::Unstake => self.unstake,
+            PermissionType::ClaimXrd => self.claim_xrd,
+            PermissionType::CallValidatorMethod => self.call_validator_method,
+            PermissionType::CallComponentMethod => self.call_component_method,
+            PermissionType::IssueAccessKeys => self.issue_access_keys,
             PermissionType::CreateValidatorOwnerBadgeProof => {
                 self.create_validator_owner_badge_proof
             }
@@ -127,22 +436,153 @@ impl AccessKeyPermissions {
             PermissionType::SetMetadata => self.set_metadata = allow,
             PermissionType::RemoveMetadata => self.remove_metadata = allow,
             PermissionType::LockMetadata => self.lock_metadata = allow,
+            PermissionType::GetRole => self.get_role = allow,
+            PermissionType::SetRole => self.set_role = allow,
+            PermissionType::StakeFromTreasury => {
+                self.stake_from_treasury = allow
+            }
+            PermissionType::CompoundOwnerRewards => {
+                self.compound_owner_rewards = allow
+            }
+            PermissionType::Unstake => self.unstake = allow,
+            PermissionType::ClaimXrd => self.claim_xrd = allow,
+            PermissionType::CallValidatorMethod => {
+                self.call_validator_method = allow
+            }
+            PermissionType::CallComponentMethod => {
+                self.call_component_method = allow
+            }
+            PermissionType::IssueAccessKeys => self.issue_access_keys = allow,
             PermissionType::CreateValidatorOwnerBadgeProof => {
                 self.create_validator_owner_badge_proof = allow
             }
         }
     }
+
+    /// The permissions these permissions effectively grant, taking into account that
+    /// `create_validator_owner_badge_proof` implicitly grants everything a validator
+    /// owner badge proof can do.
+    pub fn effective(&self) -> AccessKeyPermissions {
+        let mut effective = AccessKeyPermissions::default();
+        for permission in PermissionType::iter() {
+            let allowed = self.is_allowed(&permission)
+                || (self.create_validator_owner_badge_proof
+                    && permission.is_implied_by_validator_owner_badge_proof());
+            effective.update_permission(permission, allowed);
+        }
+        effective
+    }
+
+    /// Whether every permission allowed here is also allowed in `other`.
+    pub fn is_subset_of(&self, other: &AccessKeyPermissions) -> bool {
+        PermissionType::iter().all(|permission| {
+            !self.is_allowed(&permission) || other.is_allowed(&permission)
+        })
+    }
+}
+
+/// The validators an access key badge applies to.
+#[derive(ScryptoSbor, ManifestSbor)]
+pub enum ValidatorScope {
+    /// The access key applies to every validator managed by the component
+    All,
+    /// The access key only applies to the listed validators
+    Validators(IndexSet<ComponentAddress>),
+}
+
+impl ValidatorScope {
+    pub fn includes(&self, validator_address: &ComponentAddress) -> bool {
+        match self {
+            ValidatorScope::All => true,
+            ValidatorScope::Validators(validators) => {
+                validators.contains(validator_address)
+            }
+        }
+    }
+
+    /// Whether every validator included here is also included in `other`.
+    pub fn is_subset_of(&self, other: &ValidatorScope) -> bool {
+        match self {
+            ValidatorScope::All => matches!(other, ValidatorScope::All),
+            ValidatorScope::Validators(validators) => {
+                validators.iter().all(|validator| other.includes(validator))
+            }
+        }
+    }
+}
+
+/// A role in the role assignment module of the validator component.
+#[derive(ScryptoSbor, ManifestSbor, Clone, PartialEq, Eq, Hash)]
+pub enum ValidatorRole {
+    /// The owner role of the validator
+    Owner,
+    /// A role of the validator's main module, by its role key
+    Main(String),
+    /// A role of the validator's metadata module, by its role key (e.g. `metadata_setter`)
+    Metadata(String),
+}
+
+/// An entity owned by NodeWarden whose metadata the owner can manage.
+#[derive(
+    ScryptoSbor, ManifestSbor, Clone, Copy, PartialEq, Eq, Hash, Debug,
+)]
+pub enum NodeWardenMetadataTarget {
+    /// The NodeWarden component itself
+    Component,
+    /// The NodeWarden owner badge resource
+    OwnerBadge,
+    /// The access key badge resource
+    AccessKeyBadge,
+}
+
+/// A method on a component other than the validator, which an access key badge
+/// may call with authorization of the validator owner badge.
+#[derive(ScryptoSbor, ManifestSbor, Clone, PartialEq, Eq, Hash)]
+pub struct ComponentMethod {
+    pub component_address: ComponentAddress,
+    pub method_name: String,
 }
 
 #[derive(ScryptoSbor, NonFungibleData)]
 pub struct NodeWardenOwnerBadgeData {
     pub node_warden_component_address: ComponentAddress,
 }
-#[derive(ScryptoSbor, NonFungibleData)]
+/// The human-readable display data of an access key badge, set by the owner.
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct AccessKeyLabel {
+    pub name: String,
+    pub description: String,
+    pub key_image_url: Url,
+    /// The account the access key badge is meant for
+    pub issued_to: Option<ComponentAddress>,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, NonFungibleData)]
 pub struct AccessKeyBadgeData {
+    #[mutable] // the label should be mutable, so the owner can update it
+    pub name: String,
+    #[mutable]
+    pub description: String,
+    #[mutable]
+    pub key_image_url: Url,
+    #[mutable]
+    pub issued_to: Option<ComponentAddress>,
+    pub issued_at: Instant,
+    /// The access key badge that issued this one, if it was not issued by the owner
+    pub issuer: Option<NonFungibleLocalId>,
     pub node_warden_component_address: ComponentAddress,
     #[mutable] // permissions should be mutable, so the owner can update them
     pub permissions: AccessKeyPermissions,
+    #[mutable] // the scope should be mutable, so the owner can update it
+    pub validator_scope: ValidatorScope,
+    #[mutable] // the allowlist should be mutable, so the owner can update it
+    pub allowed_validator_methods: IndexSet<String>,
+    #[mutable] // the allowlist should be mutable, so the owner can update it
+    pub allowed_component_methods: IndexSet<ComponentMethod>,
+    #[mutable] // set by the holder, disabling the access key badge for good
+    pub compromised: bool,
+    #[mutable] // the owner can update the second factor
+    pub required_signer: Option<PublicKey>,
 }
 
 #[derive(ScryptoSbor, NonFungibleData)]
@@ -151,11 +591,555 @@ pub struct ValidatorOwnerBadgeData {
     pub validator: ComponentAddress,
 }
 
+/// The maximum number of claim NFTs that are inspected and claimed in a single call, to
+/// keep fees bounded. Further ready claim NFTs are left for the next call.
+pub const MAX_CLAIM_NFTS_PER_CALL: u32 = 100;
+
+/// The data of the claim NFTs a validator hands out when unstaking.
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct UnstakeData {
+    pub name: String,
+    pub claim_epoch: Epoch,
+    pub claim_amount: Decimal,
+}
+
+/// Claim NFTs held in custody, queued in the order the validator handed them out.
+/// As their claim epochs follow that order, the ready ones are found at the front
+/// of the queue, without loading the whole vault.
+#[derive(ScryptoSbor)]
+pub struct ClaimNftCustody {
+    /// The claim NFTs in custody
+    pub vault: NonFungibleVault,
+    /// The claim NFTs by their position in the queue, with the epoch they can be claimed in
+    pub queue: KeyValueStore<u64, (Epoch, NonFungibleLocalId)>,
+    /// The position of the oldest claim NFT in the queue
+    pub head: u64,
+    /// The position the next claim NFT is queued at
+    pub tail: u64,
+}
+
+impl ClaimNftCustody {
+    /// Creates an empty custody for claim NFTs of the given resource.
+    pub fn new(claim_nft_resource: ResourceAddress) -> Self {
+        Self {
+            vault: NonFungibleVault::new(claim_nft_resource),
+            queue: KeyValueStore::new(),
+            head: 0,
+            tail: 0,
+        }
+    }
+
+    /// Puts claim NFTs into custody, at the back of the queue.
+    pub fn put(&mut self, claim_nfts: NonFungibleBucket) {
+        for claim_nft in claim_nfts.non_fungibles::<UnstakeData>() {
+            self.queue.insert(
+                self.tail,
+                (claim_nft.data().claim_epoch, claim_nft.local_id().clone()),
+            );
+            self.tail += 1;
+        }
+        self.vault.put(claim_nfts);
+    }
+
+    /// Takes up to [`MAX_CLAIM_NFTS_PER_CALL`] claim NFTs from the front of the queue
+    /// that can be claimed in the current epoch, if there are any.
+    pub fn take_ready(&mut self) -> Option<NonFungibleBucket> {
+        let current_epoch = Runtime::current_epoch();
+        let mut ready_claim_nft_ids: IndexSet<NonFungibleLocalId> =
+            IndexSet::new();
+        while self.head < self.tail
+            && ready_claim_nft_ids.len() < MAX_CLAIM_NFTS_PER_CALL as usize
+        {
+            let (claim_epoch, local_id) =
+                self.queue.get(&self.head).unwrap().clone();
+            if claim_epoch > current_epoch {
+                break;
+            }
+            self.queue.remove(&self.head);
+            self.head += 1;
+            ready_claim_nft_ids.insert(local_id);
+        }
+        if ready_claim_nft_ids.is_empty() {
+            return None;
+        }
+        Some(self.vault.take_non_fungibles(&ready_claim_nft_ids))
+    }
+
+    /// Takes all claim NFTs out of custody, emptying the queue.
+    pub fn take_all(&mut self) -> NonFungibleBucket {
+        // The entries are left behind rather than removed one by one, so the cost
+        // does not grow with the size of the queue
+        self.head = self.tail;
+        self.vault.take_all()
+    }
+}
+
+/// The owner-defined policy that `compound_owner_rewards` follows.
+#[derive(ScryptoSbor, ManifestSbor, Default, Clone)]
+pub struct CompoundPolicy {
+    /// Unstake the unlocked stake units, and stake the XRD of any claim NFTs
+    /// that are ready to be claimed again as owner.
+    pub restake: bool,
+    /// Lock the resulting stake units in the validator again. Otherwise, they
+    /// are kept in the custody vault of NodeWarden.
+    pub relock: bool,
+}
+
+/// Emitted after every run of `compound_owner_rewards`, reporting all amounts involved.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OwnerRewardsCompoundedEvent {
+    pub validator_address: ComponentAddress,
+    /// Stake units released by finishing the ready unlocks
+    pub unlocked_stake_units: Decimal,
+    /// Stake units exchanged for a claim NFT
+    pub unstaked_stake_units: Decimal,
+    /// XRD claimed from ready claim NFTs, and staked as owner again
+    pub restaked_xrd: Decimal,
+    /// Stake units received for the restaked XRD
+    pub restaked_stake_units: Decimal,
+    /// Stake units locked in the validator again
+    pub relocked_stake_units: Decimal,
+    /// Stake units that ended up in the custody vault of NodeWarden
+    pub custodied_stake_units: Decimal,
+}
+
+/// How a migrated NodeWarden component treats the access key badges of the one
+/// it was migrated from.
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MigrationMode {
+    /// The old access key badges keep working, mapped to imported counterparts
+    /// held by the new component.
+    Honour,
+    /// New access key badges are minted and delivered to the accounts the old
+    /// ones were issued to.
+    Remint,
+}
+
+/// The state of an access key badge, carried over to another NodeWarden component.
+#[derive(ScryptoSbor, ManifestSbor)]
+pub struct AccessKeyBadgeExport {
+    pub local_id: NonFungibleLocalId,
+    pub data: AccessKeyBadgeData,
+    pub treasury_allowance: Decimal,
+    pub issuance_quota: u32,
+}
+
+/// A request of an access key holder to move their access key badge to another account.
+#[derive(ScryptoSbor, Clone)]
+pub struct AccessKeyMoveRequest {
+    /// The vault currently holding the access key badge
+    pub vault_address: InternalAddress,
+    /// The account to move the access key badge to
+    pub target_account: ComponentAddress,
+    /// The local ID of the honoured access key badge of the component migrated from
+    /// the request was made with, if any
+    pub honoured_access_key_badge: Option<NonFungibleLocalId>,
+}
+
+/// The validator a validator proxy acts on, and the badge it accepts.
+#[derive(ScryptoSbor, Clone)]
+pub struct ValidatorProxyBinding {
+    pub validator_address: ComponentAddress,
+    /// The access key badge the proxy accepts, or `None` for the NodeWarden owner badge
+    pub access_key_badge: Option<NonFungibleGlobalId>,
+}
+
+/// An action an access key holder proposes to take on a validator, to be checked
+/// by `can_perform` before submitting the transaction.
+#[derive(ScryptoSbor, ManifestSbor)]
+pub enum ProposedAction {
+    /// Any delegated method that is only guarded by a permission, e.g. `update_fee`
+    Permission(PermissionType),
+    /// `set_metadata` or `set_metadata_batch` with the given entries
+    SetMetadata(Vec<(String, MetadataValue)>),
+    /// `get_validator_role` for the given role
+    GetValidatorRole(ValidatorRole),
+    /// `set_validator_role` for the given role
+    SetValidatorRole(ValidatorRole),
+    /// `stake_from_treasury` of the given amount
+    StakeFromTreasury(Decimal),
+    /// `call_validator_method` of the given method
+    CallValidatorMethod(String),
+    /// `call_component_method` of the given method
+    CallComponentMethod(ComponentMethod),
+}
+
+impl ProposedAction {
+    /// The permission the delegated method of this action requires.
+    pub fn permission_type(&self) -> PermissionType {
+        match self {
+            ProposedAction::Permission(permission_type) => *permission_type,
+            ProposedAction::SetMetadata(_) => PermissionType::SetMetadata,
+            ProposedAction::GetValidatorRole(_) => PermissionType::GetRole,
+            ProposedAction::SetValidatorRole(_) => PermissionType::SetRole,
+            ProposedAction::StakeFromTreasury(_) => {
+                PermissionType::StakeFromTreasury
+            }
+            ProposedAction::CallValidatorMethod(_) => {
+                PermissionType::CallValidatorMethod
+            }
+            ProposedAction::CallComponentMethod(_) => {
+                PermissionType::CallComponentMethod
+            }
+        }
+    }
+}
+
+/// A summary of the configuration of a NodeWarden component, returned by `get_configuration`.
+#[derive(ScryptoSbor)]
+pub struct NodeWardenConfiguration {
+    pub owner_badge_resource: ResourceAddress,
+    pub access_key_resource: ResourceAddress,
+    /// The managed validators, mapped to the local ID of their owner badge
+    pub validators: IndexMap<ComponentAddress, NonFungibleLocalId>,
+    /// The amount of XRD in the treasury
+    pub xrd_treasury_amount: Decimal,
+    pub compound_policy: CompoundPolicy,
+    pub delegable_roles: IndexSet<ValidatorRole>,
+}
+
+/// A snapshot of the configuration of a NodeWarden component, returned by
+/// `export_configuration`. A fresh component can be instantiated from it using
+/// `new_from_snapshot`, e.g. to replicate a production setup on a test network.
+#[derive(ScryptoSbor, ManifestSbor)]
+pub struct NodeWardenSnapshot {
+    /// The managed validators
+    pub validators: Vec<ComponentAddress>,
+    pub owner_badge_resource: ResourceAddress,
+    pub access_key_resource: ResourceAddress,
+    /// The metadata the owner has set on the component and its badge resources
+    pub metadata: Vec<(NodeWardenMetadataTarget, String, MetadataValue)>,
+    /// The fields of that metadata the owner has locked
+    pub locked_metadata: Vec<(NodeWardenMetadataTarget, String)>,
+    /// The schemas delegated metadata writes on the validators must follow
+    pub metadata_schemas: Vec<(String, MetadataSchema)>,
+    pub compound_policy: CompoundPolicy,
+    pub delegable_roles: IndexSet<ValidatorRole>,
+    /// Every access key badge that has been minted, and not burned
+    pub access_key_badges: Vec<AccessKeyBadgeExport>,
+}
+
+/// Emitted when the holder of an access key badge reports it compromised.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AccessKeyCompromisedEvent {
+    pub access_key_badge_local_id: NonFungibleLocalId,
+    pub reported_at: Instant,
+}
+
+/// Emitted when an access key badge authorizes a delegated action, recording which
+/// of the access key badges in the proof was used.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AccessKeyUsedEvent {
+    pub access_key_badge_local_id: NonFungibleLocalId,
+    pub validator_address: ComponentAddress,
+    pub permission_type: PermissionType,
+}
+
+/// The kinds of [`MetadataValue`], without their contents.
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MetadataValueType {
+    String,
+    Bool,
+    U8,
+    U32,
+    U64,
+    I32,
+    I64,
+    Decimal,
+    GlobalAddress,
+    PublicKey,
+    NonFungibleGlobalId,
+    NonFungibleLocalId,
+    Instant,
+    Url,
+    Origin,
+    PublicKeyHash,
+    StringArray,
+    BoolArray,
+    U8Array,
+    U32Array,
+    U64Array,
+    I32Array,
+    I64Array,
+    DecimalArray,
+    GlobalAddressArray,
+    PublicKeyArray,
+    NonFungibleGlobalIdArray,
+    NonFungibleLocalIdArray,
+    InstantArray,
+    UrlArray,
+    OriginArray,
+    PublicKeyHashArray,
+}
+
+impl MetadataValueType {
+    /// Returns the kind of the given metadata value.
+    pub fn of(value: &MetadataValue) -> Self {
+        match value {
+            MetadataValue::String(_) => MetadataValueType::String,
+            MetadataValue::Bool(_) => MetadataValueType::Bool,
+            MetadataValue::U8(_) => MetadataValueType::U8,
+            MetadataValue::U32(_) => MetadataValueType::U32,
+            MetadataValue::U64(_) => MetadataValueType::U64,
+            MetadataValue::I32(_) => MetadataValueType::I32,
+            MetadataValue::I64(_) => MetadataValueType::I64,
+            MetadataValue::Decimal(_) => MetadataValueType::Decimal,
+            MetadataValue::GlobalAddress(_) => MetadataValueType::GlobalAddress,
+            MetadataValue::PublicKey(_) => MetadataValueType::PublicKey,
+            MetadataValue::NonFungibleGlobalId(_) => {
+                MetadataValueType::NonFungibleGlobalId
+            }
+            MetadataValue::NonFungibleLocalId(_) => {
+                MetadataValueType::NonFungibleLocalId
+            }
+            MetadataValue::Instant(_) => MetadataValueType::Instant,
+            MetadataValue::Url(_) => MetadataValueType::Url,
+            MetadataValue::Origin(_) => MetadataValueType::Origin,
+            MetadataValue::PublicKeyHash(_) => MetadataValueType::PublicKeyHash,
+            MetadataValue::StringArray(_) => MetadataValueType::StringArray,
+            MetadataValue::BoolArray(_) => MetadataValueType::BoolArray,
+            MetadataValue::U8Array(_) => MetadataValueType::U8Array,
+            MetadataValue::U32Array(_) => MetadataValueType::U32Array,
+            MetadataValue::U64Array(_) => MetadataValueType::U64Array,
+            MetadataValue::I32Array(_) => MetadataValueType::I32Array,
+            MetadataValue::I64Array(_) => MetadataValueType::I64Array,
+            MetadataValue::DecimalArray(_) => MetadataValueType::DecimalArray,
+            MetadataValue::GlobalAddressArray(_) => {
+                MetadataValueType::GlobalAddressArray
+            }
+            MetadataValue::PublicKeyArray(_) => {
+                MetadataValueType::PublicKeyArray
+            }
+            MetadataValue::NonFungibleGlobalIdArray(_) => {
+                MetadataValueType::NonFungibleGlobalIdArray
+            }
+            MetadataValue::NonFungibleLocalIdArray(_) => {
+                MetadataValueType::NonFungibleLocalIdArray
+            }
+            MetadataValue::InstantArray(_) => MetadataValueType::InstantArray,
+            MetadataValue::UrlArray(_) => MetadataValueType::UrlArray,
+            MetadataValue::OriginArray(_) => MetadataValueType::OriginArray,
+            MetadataValue::PublicKeyHashArray(_) => {
+                MetadataValueType::PublicKeyHashArray
+            }
+        }
+    }
+}
+
+/// The owner-defined schema that delegated writes of a metadata field
+/// on the validator must follow.
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct MetadataSchema {
+    /// The expected kind of value, e.g. `MetadataValueType::Url` for `info_url`.
+    pub value_type: MetadataValueType,
+    /// The maximum length of string, URL and origin values, in bytes.
+    pub max_length: Option<u32>,
+    /// The maximum number of elements of array values.
+    pub max_array_size: Option<u32>,
+    /// The origins URL and origin values must belong to,
+    /// e.g. `https://example.com`. Any origin is allowed if empty.
+    pub allowed_origins: Vec<String>,
+}
+
+impl MetadataSchema {
+    /// Checks a value for the metadata field `name` against this schema.
+    ///
+    /// # Errors
+    /// Returns the rule the value breaks, if it does not follow the schema.
+    pub fn validate(
+        &self,
+        name: &str,
+        value: &MetadataValue,
+    ) -> Result<(), NodeWardenError> {
+        if MetadataValueType::of(value) != self.value_type {
+            return Err(NodeWardenError::MetadataWrongType(name.to_string()));
+        }
+
+        let array_size = match value {
+            MetadataValue::StringArray(values) => values.len(),
+            MetadataValue::BoolArray(values) => values.len(),
+            MetadataValue::U8Array(values) => values.len(),
+            MetadataValue::U32Array(values) => values.len(),
+            MetadataValue::U64Array(values) => values.len(),
+            MetadataValue::I32Array(values) => values.len(),
+            MetadataValue::I64Array(values) => values.len(),
+            MetadataValue::DecimalArray(values) => values.len(),
+            MetadataValue::GlobalAddressArray(values) => values.len(),
+            MetadataValue::PublicKeyArray(values) => values.len(),
+            MetadataValue::NonFungibleGlobalIdArray(values) => values.len(),
+            MetadataValue::NonFungibleLocalIdArray(values) => values.len(),
+            MetadataValue::InstantArray(values) => values.len(),
+            MetadataValue::UrlArray(values) => values.len(),
+            MetadataValue::OriginArray(values) => values.len(),
+            MetadataValue::PublicKeyHashArray(values) => values.len(),
+            _ => 0,
+        };
+        if let Some(max_array_size) = self.max_array_size {
+            if array_size > max_array_size as usize {
+                return Err(NodeWardenError::MetadataTooManyElements(
+                    name.to_string(),
+                ));
+            }
+        }
+
+        let (strings, urls, origins): (Vec<&str>, Vec<&str>, Vec<&str>) =
+            match value {
+                MetadataValue::String(value) => {
+                    (vec![value.as_str()], vec![], vec![])
+                }
+                MetadataValue::StringArray(values) => (
+                    values.iter().map(|v| v.as_str()).collect(),
+                    vec![],
+                    vec![],
+                ),
+                MetadataValue::Url(value) => {
+                    (vec![], vec![value.0.as_str()], vec![])
+                }
+                MetadataValue::UrlArray(values) => (
+                    vec![],
+                    values.iter().map(|v| v.0.as_str()).collect(),
+                    vec![],
+                ),
+                MetadataValue::Origin(value) => {
+                    (vec![], vec![], vec![value.0.as_str()])
+                }
+                MetadataValue::OriginArray(values) => (
+                    vec![],
+                    vec![],
+                    values.iter().map(|v| v.0.as_str()).collect(),
+                ),
+                _ => (vec![], vec![], vec![]),
+            };
+        if let Some(max_length) = self.max_length {
+            if strings
+                .iter()
+                .chain(urls.iter())
+                .chain(origins.iter())
+                .any(|text| text.len() > max_length as usize)
+            {
+                return Err(NodeWardenError::MetadataTooLong(name.to_string()));
+            }
+        }
+        if !self.allowed_origins.is_empty() {
+            // A URL belongs to an origin if it is the origin itself, or continues
+            // with a path, query or fragment right after it.
+            let url_allowed = |url: &&str| {
+                self.allowed_origins.iter().any(|origin| {
+                    url.strip_prefix(origin.as_str()).is_some_and(|rest| {
+                        rest.is_empty() || rest.starts_with(['/', '?', '#'])
+                    })
+                })
+            };
+            if !(urls.iter().all(url_allowed)
+                && origins.iter().all(|origin| {
+                    self.allowed_origins.iter().any(|allowed| allowed == origin)
+                }))
+            {
+                return Err(NodeWardenError::MetadataOriginNotAllowed(
+                    name.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sets a metadata value of any type on a global entity with a metadata module.
+fn set_metadata_value<T: HasMetadata>(
+    target: &T,
+    name: String,
+    metadata: MetadataValue,
+) {
+    // We can't seem to get around this good old match...
+    match metadata {
+        MetadataValue::String(val) => target.set_metadata(name, val),
+        MetadataValue::Bool(val) => target.set_metadata(name, val),
+        MetadataValue::U8(val) => target.set_metadata(name, val),
+        MetadataValue::U32(val) => target.set_metadata(name, val),
+        MetadataValue::U64(val) => target.set_metadata(name, val),
+        MetadataValue::I32(val) => target.set_metadata(name, val),
+        MetadataValue::I64(val) => target.set_metadata(name, val),
+        MetadataValue::Decimal(val) => target.set_metadata(name, val),
+        MetadataValue::GlobalAddress(val) => target.set_metadata(name, val),
+        MetadataValue::PublicKey(val) => target.set_metadata(name, val),
+        MetadataValue::NonFungibleGlobalId(val) => {
+            target.set_metadata(name, val)
+        }
+        MetadataValue::NonFungibleLocalId(val) => {
+            target.set_metadata(name, val)
+        }
+        MetadataValue::Instant(val) => target.set_metadata(name, val),
+        MetadataValue::Url(val) => target.set_metadata(name, val),
+        MetadataValue::Origin(val) => target.set_metadata(name, val),
+        MetadataValue::PublicKeyHash(val) => target.set_metadata(name, val),
+        MetadataValue::StringArray(val) => target.set_metadata(name, val),
+        MetadataValue::BoolArray(val) => target.set_metadata(name, val),
+        MetadataValue::U8Array(val) => target.set_metadata(name, val),
+        MetadataValue::U32Array(val) => target.set_metadata(name, val),
+        MetadataValue::U64Array(val) => target.set_metadata(name, val),
+        MetadataValue::I32Array(val) => target.set_metadata(name, val),
+        MetadataValue::I64Array(val) => target.set_metadata(name, val),
+        MetadataValue::DecimalArray(val) => target.set_metadata(name, val),
+        MetadataValue::GlobalAddressArray(val) => {
+            target.set_metadata(name, val)
+        }
+        MetadataValue::PublicKeyArray(val) => target.set_metadata(name, val),
+        MetadataValue::NonFungibleGlobalIdArray(val) => {
+            target.set_metadata(name, val)
+        }
+        MetadataValue::NonFungibleLocalIdArray(val) => {
+            target.set_metadata(name, val)
+        }
+        MetadataValue::InstantArray(val) => target.set_metadata(name, val),
+        MetadataValue::UrlArray(val) => target.set_metadata(name, val),
+        MetadataValue::OriginArray(val) => target.set_metadata(name, val),
+        MetadataValue::PublicKeyHashArray(val) => {
+            target.set_metadata(name, val)
+        }
+    }
+}
+
+/// Asserts the transaction is signed by the second factor of an access key badge, if any.
+fn assert_required_signer(access_key_badge_data: &AccessKeyBadgeData) {
+    if let Some(required_signer) = &access_key_badge_data.required_signer {
+        Runtime::assert_access_rule(rule!(require(
+            NonFungibleGlobalId::from_public_key(required_signer)
+        )));
+    }
+}
+
+/// Calls a method on a global component with the given arguments, without
+/// knowing its interface at compile time.
+///
+/// # Panics
+/// Panics if the arguments are not a tuple.
+fn call_method_raw(
+    component_address: ComponentAddress,
+    method_name: &str,
+    args: ScryptoValue,
+) -> ScryptoValue {
+    if !matches!(args, ScryptoValue::Tuple { .. }) {
+        NodeWardenError::ArgumentsNotATuple.raise();
+    }
+    let output = ScryptoVmV1Api::object_call(
+        component_address.as_node_id(),
+        method_name,
+        scrypto_encode(&args).unwrap(),
+    );
+    scrypto_decode(&output).unwrap()
+}
+
 #[blueprint]
+#[events(
+    OwnerRewardsCompoundedEvent,
+    AccessKeyCompromisedEvent,
+    AccessKeyUsedEvent
+)]
 mod node_warden {
     enable_function_auth! {
         new => rule!(allow_all);
         new_with_address_reservation => rule!(allow_all);
+        new_from_snapshot => rule!(allow_all);
     }
     enable_method_auth! {
         roles {
@@ -167,9 +1151,34 @@ mod node_warden {
             deposit_validator_owner_badge => restrict_to: [component_owner];
             create_access_key_badge => restrict_to: [component_owner];
             recall_access_key_badge => restrict_to: [component_owner];
+            rekey_access_key_badge => restrict_to: [component_owner];
             burn_access_key_badge => restrict_to: [component_owner];
             update_access_key_badge_permissions => restrict_to: [component_owner];
+            update_access_key_badge_validator_scope => restrict_to: [component_owner];
+            update_access_key_badge_label => restrict_to: [component_owner];
+            update_access_key_badge_required_signer => restrict_to: [component_owner];
+            update_access_key_badge_issuance_quota => restrict_to: [component_owner];
+            approve_access_key_badge_move => restrict_to: [component_owner];
+            reject_access_key_badge_move => restrict_to: [component_owner];
+            update_access_key_badge_validator_methods => restrict_to: [component_owner];
+            update_access_key_badge_component_methods => restrict_to: [component_owner];
+            update_delegable_role => restrict_to: [component_owner];
+            update_metadata_schema => restrict_to: [component_owner];
+            set_node_warden_metadata => restrict_to: [component_owner];
+            remove_node_warden_metadata => restrict_to: [component_owner];
+            lock_node_warden_metadata => restrict_to: [component_owner];
             withdraw_validator_owner_badge => restrict_to: [component_owner];
+            deposit_owner_stake_units => restrict_to: [component_owner];
+            withdraw_owner_stake_units => restrict_to: [component_owner];
+            deposit_treasury_xrd => restrict_to: [component_owner];
+            withdraw_treasury_xrd => restrict_to: [component_owner];
+            update_access_key_badge_treasury_allowance => restrict_to: [component_owner];
+            update_compound_policy => restrict_to: [component_owner];
+            withdraw_claim_nfts => restrict_to: [component_owner];
+            create_validator_proxy => restrict_to: [component_owner];
+            accept_migration => restrict_to: [component_owner];
+            migrate => restrict_to: [component_owner];
+            import_snapshot => restrict_to: [component_owner];
 
             // These methods mimic the validator component's interface
             // These are public, because their access is not managed by
@@ -189,22 +1198,126 @@ mod node_warden {
             set_metadata => PUBLIC;
             remove_metadata => PUBLIC;
             lock_metadata => PUBLIC;
+            set_metadata_batch => PUBLIC;
+            remove_metadata_batch => PUBLIC;
+
+            // Additional methods for managing roles
+            get_validator_role => PUBLIC;
+            set_validator_role => PUBLIC;
+
+            // Additional method for staking from the XRD treasury
+            stake_from_treasury => PUBLIC;
+
+            // Additional method for compounding owner rewards
+            compound_owner_rewards => PUBLIC;
+
+            // Additional methods for exiting custodied owner stake
+            unstake => PUBLIC;
+            claim_xrd => PUBLIC;
+
+            // Additional methods for calling allowlisted methods
+            call_validator_method => PUBLIC;
+            call_component_method => PUBLIC;
 
              // Additional method - warning: powerful
             create_validator_owner_badge_proof => PUBLIC;
+
+            // Additional methods for issuing access keys as an access key holder
+            issue_access_key_badge => PUBLIC;
+            recall_and_burn_issued_access_key_badge => PUBLIC;
+
+            // Additional methods for access key holders to manage their own access key
+            renounce_access_key_badge_permissions => PUBLIC;
+            report_access_key_badge_compromised => PUBLIC;
+            request_access_key_badge_move => PUBLIC;
+
+            // Lends the validator owner badge to the validator proxies, which check
+            // the auth zone of their caller themselves.
+            create_validator_proxy_proof => PUBLIC;
+            deposit_validator_proxy_stake_units => PUBLIC;
+
+            // Receives a migration, checking the calling component itself
+            import_migration => PUBLIC;
+
+            // View methods
+            get_validator_addresses => PUBLIC;
+            has_validator_owner_badge => PUBLIC;
+            get_access_key_permissions => PUBLIC;
+            get_owner_badge_resource => PUBLIC;
+            get_access_key_resource => PUBLIC;
+            get_configuration => PUBLIC;
+            get_access_key_badge_successor => PUBLIC;
+            get_effective_permissions => PUBLIC;
+            can => PUBLIC;
+            can_perform => PUBLIC;
+            get_access_rule => PUBLIC;
+            get_validator_proxy => PUBLIC;
+            get_honoured_access_key_badge => PUBLIC;
+            export_configuration => PUBLIC;
         }
     }
     struct NodeWarden {
-        // The vault holding the validator owner badge - the badge that controls the validator
+        // The vault holding the validator owner badges - the badges that control the validators
         validator_owner_badge: NonFungibleVault,
         // The resource manager of the owner badge of this component
         node_warden_owner_badge_resource_manager: NonFungibleResourceManager,
         // The resource manager of the access key badges
         access_key_badge_resource_manager: NonFungibleResourceManager,
-        // The component address of the validator component we are
-        // currently managing. This can be None, if there is no validator
-        // badge currently inside this component.
-        validator_address: Option<ComponentAddress>,
+        // The component addresses of the validator components we are
+        // currently managing, mapped to the local ID of their owner badge
+        // inside the `validator_owner_badge` vault.
+        validators: IndexMap<ComponentAddress, NonFungibleLocalId>,
+        // The vaults holding owner stake units in custody, per validator.
+        owner_stake_units: KeyValueStore<ComponentAddress, FungibleVault>,
+        // The XRD treasury funded by the owner, used for staking by delegates.
+        xrd_treasury: FungibleVault,
+        // The amount of XRD each access key badge may still stake from the treasury.
+        treasury_allowances: KeyValueStore<NonFungibleLocalId, Decimal>,
+        // The claim NFTs of unstaked owner stake units in custody, per validator.
+        claim_nfts: KeyValueStore<ComponentAddress, ClaimNftCustody>,
+        // The claim NFTs of `compound_owner_rewards` until they are restaked, per
+        // validator. Kept apart so exiting stake is never restaked.
+        compound_claim_nfts: KeyValueStore<ComponentAddress, ClaimNftCustody>,
+        // The policy `compound_owner_rewards` follows.
+        compound_policy: CompoundPolicy,
+        // The roles of the validators that access key badges may read and set.
+        delegable_roles: IndexSet<ValidatorRole>,
+        // The number of access key badges each access key badge may still issue.
+        issuance_quotas: KeyValueStore<NonFungibleLocalId, u32>,
+        // The pending requests to move access key badges to other accounts.
+        move_requests: KeyValueStore<NonFungibleLocalId, AccessKeyMoveRequest>,
+        // The replacements of re-keyed access key badges.
+        successors: KeyValueStore<NonFungibleLocalId, NonFungibleLocalId>,
+        // The schemas delegated metadata writes on the validators must follow, per field.
+        metadata_schemas: IndexMap<String, MetadataSchema>,
+        // The local IDs of all access key badges minted by this component, including burned
+        // ones, by the order they were minted in. Kept out of the root state, as it keeps growing.
+        access_key_badges: KeyValueStore<u64, NonFungibleLocalId>,
+        // The number of access key badges minted, and the index the next one is recorded at.
+        access_key_badge_count: u64,
+        // The proxy components mirroring the native interface, with what they are bound to.
+        validator_proxies:
+            KeyValueStore<ComponentAddress, ValidatorProxyBinding>,
+        // The current proxy per validator and badge, to look it up and replace it.
+        validator_proxy_addresses: KeyValueStore<
+            (ComponentAddress, Option<NonFungibleGlobalId>),
+            ComponentAddress,
+        >,
+        // The NodeWarden component the owner has accepted a migration from.
+        migration_source: Option<ComponentAddress>,
+        // The access key badge resource of the component migrated from, whose badges are honoured.
+        honoured_access_key_resource: Option<ResourceAddress>,
+        // The imported access key badges standing in for the honoured ones, by their old local ID.
+        honoured_access_keys:
+            KeyValueStore<NonFungibleLocalId, NonFungibleLocalId>,
+        // The vault holding the imported access key badges on behalf of the honoured ones.
+        honoured_access_key_vault: NonFungibleVault,
+        // The metadata the owner has set on this component and its badge resources.
+        node_warden_metadata:
+            IndexMap<(NodeWardenMetadataTarget, String), MetadataValue>,
+        // The metadata fields the owner has locked on this component and its badge resources.
+        locked_node_warden_metadata:
+            IndexSet<(NodeWardenMetadataTarget, String)>,
     }
     impl NodeWarden {
         /// Creates a new NodeWarden instance.
@@ -318,7 +1431,8 @@ mod node_warden {
                     withdrawer_updater => rule!(deny_all);
                 ))
                 .deposit_roles(deposit_roles! (
-                    depositor => rule!(require(node_warden_owner_badge.resource_address()));
+                    // NodeWarden itself delivers the access key badges issued by access key holders
+                    depositor => rule!(require(node_warden_owner_badge.resource_address()) || require(global_caller(component_address)));
                     depositor_updater => rule!(deny_all);
                 ))
                 .recall_roles(recall_roles! (
@@ -348,8 +1462,29 @@ mod node_warden {
             let component = Self {
                     validator_owner_badge: NonFungibleVault::new(VALIDATOR_OWNER_BADGE),
                     node_warden_owner_badge_resource_manager: node_warden_owner_badge.resource_manager(),
-                    access_key_badge_resource_manager: access_key_badge_resource_manager,
-                    validator_address: None
+                    access_key_badge_resource_manager,
+                    validators: IndexMap::new(),
+                    owner_stake_units: KeyValueStore::new(),
+                    xrd_treasury: FungibleVault::new(XRD),
+                    treasury_allowances: KeyValueStore::new(),
+                    claim_nfts: KeyValueStore::new(),
+                    compound_claim_nfts: KeyValueStore::new(),
+                    compound_policy: CompoundPolicy::default(),
+                    delegable_roles: IndexSet::new(),
+                    issuance_quotas: KeyValueStore::new(),
+                    move_requests: KeyValueStore::new(),
+                    successors: KeyValueStore::new(),
+                    metadata_schemas: IndexMap::new(),
+                    access_key_badges: KeyValueStore::new(),
+                    access_key_badge_count: 0,
+                    validator_proxies: KeyValueStore::new(),
+                    validator_proxy_addresses: KeyValueStore::new(),
+                    migration_source: None,
+                    honoured_access_key_resource: None,
+                    honoured_access_keys: KeyValueStore::new(),
+                    honoured_access_key_vault: NonFungibleVault::new(access_key_badge_resource_manager.address()),
+                    node_warden_metadata: IndexMap::new(),
+                    locked_node_warden_metadata: IndexSet::new(),
                 }
                 .instantiate()
                 .prepare_to_globalize(OwnerRole::Updatable(rule!(require(global_caller(component_address)))))
@@ -385,17 +1520,58 @@ mod node_warden {
             (component, node_warden_owner_badge)
         }
 
+        /// Creates a new NodeWarden instance from a snapshot of another one, taken using
+        /// `export_configuration`. See `import_snapshot` for what is restored.
+        ///
+        /// * `snapshot` - The snapshot to restore.
+        /// * `recipient` - The account to deposit the re-minted access key badges into,
+        ///   for the owner to hand out.
+        pub fn new_from_snapshot(
+            dapp_definition: Option<ComponentAddress>,
+            snapshot: NodeWardenSnapshot,
+            recipient: Global<Account>,
+        ) -> (Global<NodeWarden>, NonFungibleBucket) {
+            let (component, node_warden_owner_badge) =
+                Self::new(dapp_definition);
+            node_warden_owner_badge.authorize_with_all(|| {
+                component.import_snapshot(snapshot, recipient)
+            });
+            (component, node_warden_owner_badge)
+        }
+
         /// Create a new access key badge with the given permissions.
+        ///
+        /// * `permissions`: The permissions of the new access key badge.
+        /// * `validator_scope`: The validators the new access key badge applies to.
+        /// * `label`: The display data of the new access key badge.
         pub fn create_access_key_badge(
-            &self,
+            &mut self,
             permissions: AccessKeyPermissions,
+            validator_scope: ValidatorScope,
+            label: AccessKeyLabel,
         ) -> NonFungibleBucket {
             let access_key_badge_data = AccessKeyBadgeData {
+                name: label.name,
+                description: label.description,
+                key_image_url: label.key_image_url,
+                issued_to: label.issued_to,
+                issued_at: Clock::current_time_rounded_to_seconds(),
+                issuer: None,
                 node_warden_component_address: Runtime::global_address(),
                 permissions,
+                validator_scope,
+                allowed_validator_methods: IndexSet::new(),
+                allowed_component_methods: IndexSet::new(),
+                compromised: false,
+                required_signer: None,
             };
-            self.access_key_badge_resource_manager
-                .mint_ruid_non_fungible(access_key_badge_data)
+            let access_key_badge = self
+                .access_key_badge_resource_manager
+                .mint_ruid_non_fungible(access_key_badge_data);
+            self.record_access_key_badge(
+                access_key_badge.non_fungible_local_id(),
+            );
+            access_key_badge
         }
 
         /// Update a permissions of an existing access key badge.
@@ -422,7 +1598,10 @@ mod node_warden {
             // This should be safe: if the string does not correspond
             // to a valid PermissionType, it will just panic.
             let permission_type = PermissionType::from_str(&permission)
-                .expect("Invalid permission type");
+                .unwrap_or_else(|_| {
+                    NodeWardenError::InvalidPermission(permission.clone())
+                        .raise()
+                });
 
             // MUTABLY update the access key badge data with the new permission.
             access_key_badge_data
@@ -438,500 +1617,3039 @@ mod node_warden {
                 );
         }
 
-        /// Recalls the key badge from the specified vault.
-        pub fn recall_access_key_badge(
+        /// Update the validators an existing access key badge applies to.
+        ///
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `validator_scope`: The new validator scope of the access key badge.
+        pub fn update_access_key_badge_validator_scope(
             &self,
-            vault_address: InternalAddress,
-            id: NonFungibleLocalId,
-        ) -> NonFungibleBucket {
-            let recalled_bucket: NonFungibleBucket =
-                scrypto_decode(&ScryptoVmV1Api::object_call_direct(
-                    vault_address.as_node_id(),
-                    NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
-                    scrypto_args!(vec!(id)),
-                ))
-                .unwrap();
-            recalled_bucket
+            access_key_badge_local_id: NonFungibleLocalId,
+            validator_scope: ValidatorScope,
+        ) {
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(
+                    &access_key_badge_local_id,
+                    "validator_scope",
+                    validator_scope,
+                );
         }
 
-        /// Burns the given key badge.
-        pub fn burn_access_key_badge(&self, key_badge: NonFungibleBucket) {
-            key_badge.burn();
+        /// Bind the usage of an access key badge to a second factor. Transactions using
+        /// the access key badge then also need to be signed by the given public key,
+        /// such as that of a hardware key.
+        ///
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `required_signer`: The public key that must sign, or `None` to remove the requirement.
+        pub fn update_access_key_badge_required_signer(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            required_signer: Option<PublicKey>,
+        ) {
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(
+                    &access_key_badge_local_id,
+                    "required_signer",
+                    required_signer,
+                );
         }
 
-        /// Deposit the validator owner badge into this component.
+        /// Set the number of access key badges an access key badge may issue.
         ///
-        /// # Panics
-        /// Panics if the component already has a validator owner badge or if the
-        /// given bucket does not contain exactly one validator owner badge.
-        pub fn deposit_validator_owner_badge(
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `quota`: The number of access key badges it may issue from now on.
+        pub fn update_access_key_badge_issuance_quota(
             &mut self,
-            bucket: NonFungibleBucket,
+            access_key_badge_local_id: NonFungibleLocalId,
+            quota: u32,
         ) {
-            assert!(
-                bucket.resource_address() == VALIDATOR_OWNER_BADGE,
-                "The deposited bucket must be a validator owner badge"
-            );
-            assert!(
-                self.validator_owner_badge.is_empty(),
-                "There should be no validator owner badge already deposited."
-            );
-            assert!(
-                bucket.amount() == Decimal::ONE,
-                "The deposited bucket must contain exactly one validator owner badge."
-            );
-            self.validator_owner_badge.put(bucket);
-
-            // Update this component to reflect the new validator address
-            let nft_data: ValidatorOwnerBadgeData =
-                self.validator_owner_badge.non_fungible().data();
-            self.validator_address = Some(nft_data.validator);
+            self.issuance_quotas
+                .insert(access_key_badge_local_id, quota);
         }
 
-        /// Withdraw the validator owner badge from this component, leaving it unable to control the validator.
-        ///
-        /// # Returns
-        ///
-        /// The withdrawn validator owner badge.
-        ///
-        /// # Panics
-        /// Panics if there is no validator owner badge to withdraw.
+        /// Approve the pending request to move an access key badge to another account.
+        /// The access key badge is recalled and deposited into the target account, keeping
+        /// its local ID, permissions and history. Its `issued_to` is set to the target account.
+        /// For an honoured access key badge, its imported counterpart is delivered
+        /// instead, and the old access key badge stops working on this component.
         ///
-        pub fn withdraw_validator_owner_badge(&mut self) -> NonFungibleBucket {
-            // There should be exactly one validator owner badge to withdraw.
-            assert!(
-                self.validator_owner_badge.amount() == Decimal::ONE,
-                "There is no validator owner badge to withdraw"
-            );
-            // Set the validator address to None - it's no longer managed by this component.
-            self.validator_address = None;
-            self.validator_owner_badge.take(1)
-        }
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to move.
+        pub fn approve_access_key_badge_move(
+            &mut self,
+            access_key_badge_local_id: NonFungibleLocalId,
+        ) {
+            let request = self
+                .move_requests
+                .remove(&access_key_badge_local_id)
+                .unwrap_or_else(|| {
+                    NodeWardenError::NoPendingMoveRequest.raise()
+                });
 
-        // ##############################################################
-        // ##### Methods that correspond to the Validator component #####
-        // ##############################################################
+            let access_key_badge = match request.honoured_access_key_badge {
+                // The imported counterpart is delivered instead, and the honoured
+                // access key badge stops standing in for it
+                Some(honoured_access_key_badge) => {
+                    self.honoured_access_keys
+                        .remove(&honoured_access_key_badge);
+                    self.honoured_access_key_vault
+                        .take_non_fungible(&access_key_badge_local_id)
+                }
+                None => self.recall_access_key_badge(
+                    request.vault_address,
+                    access_key_badge_local_id.clone(),
+                ),
+            };
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(
+                    &access_key_badge_local_id,
+                    "issued_to",
+                    Some(request.target_account),
+                );
+            let mut target_account: Global<Account> =
+                request.target_account.into();
+            target_account.try_deposit_or_abort(access_key_badge.into(), None);
+        }
 
-        /// Stakes an amount of XRD to the Validator in exchange for Stake Units.
+        /// Reject the pending request to move an access key badge to another account.
         ///
-        /// This method is different from the regular `stake` method, in the
+        /// * `access_key_badge_local_id`: The local ID of the access key badge.
+        pub fn reject_access_key_badge_move(
+            &mut self,
+            access_key_badge_local_id: NonFungibleLocalId,
+        ) {
+            self.move_requests
+                .remove(&access_key_badge_local_id)
+                .unwrap_or_else(|| {
+                    NodeWardenError::NoPendingMoveRequest.raise()
+                });
+        }
+
+        /// Update the label of an existing access key badge.
+        ///
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `label`: The new display data of the access key badge.
+        pub fn update_access_key_badge_label(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            label: AccessKeyLabel,
+        ) {
+            let resource_manager = &self.access_key_badge_resource_manager;
+            resource_manager.update_non_fungible_data(
+                &access_key_badge_local_id,
+                "name",
+                label.name,
+            );
+            resource_manager.update_non_fungible_data(
+                &access_key_badge_local_id,
+                "description",
+                label.description,
+            );
+            resource_manager.update_non_fungible_data(
+                &access_key_badge_local_id,
+                "key_image_url",
+                label.key_image_url,
+            );
+            resource_manager.update_non_fungible_data(
+                &access_key_badge_local_id,
+                "issued_to",
+                label.issued_to,
+            );
+        }
+
+        /// Allow or disallow an existing access key badge to call a validator method
+        /// through `call_validator_method`.
+        ///
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `method_name`: The name of the method on the `Validator` component.
+        /// * `allow`: Whether to allow or disallow the method.
+        pub fn update_access_key_badge_validator_methods(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            method_name: String,
+            allow: bool,
+        ) {
+            let mut allowed_validator_methods = self
+                .access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(
+                    &access_key_badge_local_id,
+                )
+                .allowed_validator_methods;
+
+            if allow {
+                allowed_validator_methods.insert(method_name);
+            } else {
+                allowed_validator_methods.swap_remove(&method_name);
+            }
+
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(
+                    &access_key_badge_local_id,
+                    "allowed_validator_methods",
+                    allowed_validator_methods,
+                );
+        }
+
+        /// Allow or disallow an existing access key badge to call a method on another component
+        /// through `call_component_method`.
+        ///
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `component_method`: The component and the name of the method on it.
+        /// * `allow`: Whether to allow or disallow the method.
+        pub fn update_access_key_badge_component_methods(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            component_method: ComponentMethod,
+            allow: bool,
+        ) {
+            let mut allowed_component_methods = self
+                .access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(
+                    &access_key_badge_local_id,
+                )
+                .allowed_component_methods;
+
+            if allow {
+                allowed_component_methods.insert(component_method);
+            } else {
+                allowed_component_methods.swap_remove(&component_method);
+            }
+
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(
+                    &access_key_badge_local_id,
+                    "allowed_component_methods",
+                    allowed_component_methods,
+                );
+        }
+
+        /// Recalls the key badge from the specified vault.
+        pub fn recall_access_key_badge(
+            &self,
+            vault_address: InternalAddress,
+            id: NonFungibleLocalId,
+        ) -> NonFungibleBucket {
+            let recalled_bucket: NonFungibleBucket =
+                scrypto_decode(&ScryptoVmV1Api::object_call_direct(
+                    vault_address.as_node_id(),
+                    NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+                    scrypto_args!(vec!(id)),
+                ))
+                .unwrap();
+            recalled_bucket
+        }
+
+        /// Burns the given key badge.
+        pub fn burn_access_key_badge(&self, key_badge: NonFungibleBucket) {
+            key_badge.burn();
+        }
+
+        /// Replace an access key badge with a new one delivered to another account,
+        /// e.g. when the account of a delegate is suspected to be compromised.
+        /// The old access key badge is recalled and burned. The replacement keeps its
+        /// permissions, validator scope, allowlists, label, treasury allowance and
+        /// issuance quota, but not a compromise report. The link to the replacement
+        /// can be queried using `get_access_key_badge_successor`.
+        ///
+        /// * `vault_address`: The vault holding the access key badge to replace.
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to replace.
+        /// * `new_account`: The account to deliver the replacement to.
+        ///
+        /// # Returns
+        ///
+        /// The local ID of the replacement.
+        pub fn rekey_access_key_badge(
+            &mut self,
+            vault_address: InternalAddress,
+            access_key_badge_local_id: NonFungibleLocalId,
+            mut new_account: Global<Account>,
+        ) -> NonFungibleLocalId {
+            let old_data = self
+                .access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(
+                &access_key_badge_local_id,
+            );
+            self.recall_access_key_badge(
+                vault_address,
+                access_key_badge_local_id.clone(),
+            )
+            .burn();
+
+            let access_key_badge = self
+                .access_key_badge_resource_manager
+                .mint_ruid_non_fungible(AccessKeyBadgeData {
+                    issued_to: Some(new_account.address()),
+                    issued_at: Clock::current_time_rounded_to_seconds(),
+                    // The replacement is held by a fresh account, so a compromise
+                    // report against the old access key badge does not carry over
+                    compromised: false,
+                    ..old_data
+                });
+            let new_local_id = access_key_badge.non_fungible_local_id();
+            self.record_access_key_badge(new_local_id.clone());
+
+            // Carry over the state kept for the old access key badge
+            if let Some(allowance) =
+                self.treasury_allowances.remove(&access_key_badge_local_id)
+            {
+                self.treasury_allowances
+                    .insert(new_local_id.clone(), allowance);
+            }
+            if let Some(quota) =
+                self.issuance_quotas.remove(&access_key_badge_local_id)
+            {
+                self.issuance_quotas.insert(new_local_id.clone(), quota);
+            }
+            self.move_requests.remove(&access_key_badge_local_id);
+            self.successors
+                .insert(access_key_badge_local_id, new_local_id.clone());
+
+            new_account.try_deposit_or_abort(access_key_badge.into(), None);
+            new_local_id
+        }
+
+        /// Deposit one or more validator owner badges into this component.
+        ///
+        /// # Panics
+        /// Panics if the given bucket does not contain validator owner badges, or if
+        /// the component already holds the owner badge of one of the validators.
+        pub fn deposit_validator_owner_badge(
+            &mut self,
+            bucket: NonFungibleBucket,
+        ) {
+            if bucket.resource_address() != VALIDATOR_OWNER_BADGE {
+                NodeWardenError::NotAValidatorOwnerBadge.raise();
+            }
+            if bucket.is_empty() {
+                NodeWardenError::NoValidatorOwnerBadgeDeposited.raise();
+            }
+
+            // Register every validator whose badge is in the bucket
+            for non_fungible in
+                bucket.non_fungibles::<ValidatorOwnerBadgeData>()
+            {
+                let validator_address = non_fungible.data().validator;
+                if self.validators.contains_key(&validator_address) {
+                    NodeWardenError::ValidatorOwnerBadgeAlreadyDeposited(
+                        validator_address,
+                    )
+                    .raise();
+                }
+                self.validators
+                    .insert(validator_address, non_fungible.local_id().clone());
+            }
+            self.validator_owner_badge.put(bucket);
+        }
+
+        /// Withdraw the owner badge of the given validator from this component, leaving
+        /// it unable to control that validator.
+        ///
+        /// * `validator_address` - The component address of the validator to release.
+        ///
+        /// # Returns
+        ///
+        /// The withdrawn validator owner badge.
+        ///
+        /// # Panics
+        /// Panics if there is no owner badge for the given validator to withdraw.
+        ///
+        pub fn withdraw_validator_owner_badge(
+            &mut self,
+            validator_address: ComponentAddress,
+        ) -> NonFungibleBucket {
+            // Remove the validator - it's no longer managed by this component.
+            let non_fungible_id = self
+                .validators
+                .swap_remove(&validator_address)
+                .unwrap_or_else(|| {
+                    NodeWardenError::NoValidatorOwnerBadge(validator_address)
+                        .raise()
+                });
+            self.validator_owner_badge
+                .take_non_fungible(&non_fungible_id)
+        }
+
+        /// Deposit owner stake units of the given validator into the custody of this component.
+        ///
+        /// Custodied stake units can be locked by delegates using `lock_owner_stake_units`,
+        /// without the delegates ever possessing them.
+        ///
+        /// * `validator_address` - The validator the stake units belong to.
+        /// * `stake_units` - A bucket of stake units of the validator.
+        ///
+        /// # Panics
+        /// Panics if the bucket does not contain the stake units of the given validator.
+        pub fn deposit_owner_stake_units(
+            &mut self,
+            validator_address: ComponentAddress,
+            stake_units: FungibleBucket,
+        ) {
+            if stake_units.resource_address()
+                != Self::stake_unit_resource(validator_address)
+            {
+                NodeWardenError::NotStakeUnitsOfValidator(validator_address)
+                    .raise();
+            }
+            self.owner_stake_unit_vault(validator_address)
+                .put(stake_units);
+        }
+
+        /// Withdraw custodied owner stake units of the given validator from this component.
+        ///
+        /// * `validator_address` - The validator the stake units belong to.
+        /// * `amount` - The amount of stake units to withdraw.
+        ///
+        /// # Returns
+        ///
+        /// A bucket of stake units of the validator.
+        pub fn withdraw_owner_stake_units(
+            &mut self,
+            validator_address: ComponentAddress,
+            amount: Decimal,
+        ) -> FungibleBucket {
+            self.owner_stake_unit_vault(validator_address).take(amount)
+        }
+
+        /// Deposit XRD into the treasury of this component.
+        ///
+        /// Delegates with the `stake_from_treasury` permission can stake this XRD
+        /// as owner, within the allowance of their access key badge.
+        ///
+        /// * `xrd` - A bucket of XRD.
+        pub fn deposit_treasury_xrd(&mut self, xrd: FungibleBucket) {
+            self.xrd_treasury.put(xrd);
+        }
+
+        /// Withdraw XRD from the treasury of this component.
+        ///
+        /// * `amount` - The amount of XRD to withdraw.
+        ///
+        /// # Returns
+        ///
+        /// A bucket of XRD.
+        pub fn withdraw_treasury_xrd(
+            &mut self,
+            amount: Decimal,
+        ) -> FungibleBucket {
+            self.xrd_treasury.take(amount)
+        }
+
+        /// Set the amount of XRD an access key badge may stake from the treasury.
+        ///
+        /// * `access_key_badge_local_id`: The local ID of the access key badge to update.
+        /// * `allowance`: The amount of XRD the access key badge may stake from now on.
+        pub fn update_access_key_badge_treasury_allowance(
+            &mut self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            allowance: Decimal,
+        ) {
+            if allowance < Decimal::ZERO {
+                NodeWardenError::NegativeAllowance.raise();
+            }
+            self.treasury_allowances
+                .insert(access_key_badge_local_id, allowance);
+        }
+
+        /// Withdraw all custodied claim NFTs of the given validator from this component,
+        /// including those `compound_owner_rewards` has yet to restake.
+        ///
+        /// * `validator_address` - The validator the claim NFTs belong to.
+        ///
+        /// # Returns
+        ///
+        /// A bucket of claim NFTs of the validator.
+        pub fn withdraw_claim_nfts(
+            &mut self,
+            validator_address: ComponentAddress,
+        ) -> NonFungibleBucket {
+            let mut claim_nfts =
+                self.claim_nft_custody(validator_address).take_all();
+            claim_nfts.put(
+                self.compound_claim_nft_custody(validator_address)
+                    .take_all(),
+            );
+            claim_nfts
+        }
+
+        /// Update the policy `compound_owner_rewards` follows.
+        ///
+        /// * `compound_policy`: The new compound policy.
+        pub fn update_compound_policy(
+            &mut self,
+            compound_policy: CompoundPolicy,
+        ) {
+            self.compound_policy = compound_policy;
+        }
+
+        /// Allow or disallow access key badges to read and set a role of the validators.
+        ///
+        /// * `role`: The role of the validator component.
+        /// * `allow`: Whether access key badges may manage the role.
+        pub fn update_delegable_role(
+            &mut self,
+            role: ValidatorRole,
+            allow: bool,
+        ) {
+            if allow {
+                self.delegable_roles.insert(role);
+            } else {
+                self.delegable_roles.swap_remove(&role);
+            }
+        }
+
+        /// Set or clear the schema of a metadata field on the validators.
+        /// Metadata set through an access key badge must follow the schema of its field.
+        /// Fields without a schema accept any value.
+        ///
+        /// * `name`: The name of the metadata field.
+        /// * `schema`: The new schema, or `None` to clear it.
+        pub fn update_metadata_schema(
+            &mut self,
+            name: String,
+            schema: Option<MetadataSchema>,
+        ) {
+            match schema {
+                Some(schema) => {
+                    self.metadata_schemas.insert(name, schema);
+                }
+                None => {
+                    self.metadata_schemas.shift_remove(&name);
+                }
+            }
+        }
+
+        /// Set metadata on this component or one of its badge resources,
+        /// e.g. `icon_url`, `info_url` or `key_image_url`.
+        /// Locked fields cannot be changed.
+        ///
+        /// * `target`: The entity to set the metadata on.
+        /// * `name`: The name of the metadata field.
+        /// * `metadata`: The metadata value to set.
+        pub fn set_node_warden_metadata(
+            &mut self,
+            target: NodeWardenMetadataTarget,
+            name: String,
+            metadata: MetadataValue,
+        ) {
+            self.node_warden_metadata
+                .insert((target, name.clone()), metadata.clone());
+            match target {
+                NodeWardenMetadataTarget::Component => set_metadata_value(
+                    &Runtime::global_component(),
+                    name,
+                    metadata,
+                ),
+                NodeWardenMetadataTarget::OwnerBadge => set_metadata_value(
+                    &*self.node_warden_owner_badge_resource_manager,
+                    name,
+                    metadata,
+                ),
+                NodeWardenMetadataTarget::AccessKeyBadge => set_metadata_value(
+                    &*self.access_key_badge_resource_manager,
+                    name,
+                    metadata,
+                ),
+            }
+        }
+
+        /// Remove metadata from this component or one of its badge resources.
+        /// Locked fields cannot be removed.
+        ///
+        /// * `target`: The entity to remove the metadata from.
+        /// * `name`: The name of the metadata field.
+        pub fn remove_node_warden_metadata(
+            &mut self,
+            target: NodeWardenMetadataTarget,
+            name: String,
+        ) {
+            self.node_warden_metadata
+                .shift_remove(&(target, name.clone()));
+            match target {
+                NodeWardenMetadataTarget::Component => {
+                    Runtime::global_component().remove_metadata(name);
+                }
+                NodeWardenMetadataTarget::OwnerBadge => {
+                    self.node_warden_owner_badge_resource_manager
+                        .remove_metadata(name);
+                }
+                NodeWardenMetadataTarget::AccessKeyBadge => {
+                    self.access_key_badge_resource_manager
+                        .remove_metadata(name);
+                }
+            }
+        }
+
+        /// Lock a piece of metadata on this component or one of its badge resources,
+        /// after which it cannot be changed or removed.
+        ///
+        /// * `target`: The entity to lock the metadata on.
+        /// * `name`: The name of the metadata field.
+        pub fn lock_node_warden_metadata(
+            &mut self,
+            target: NodeWardenMetadataTarget,
+            name: String,
+        ) {
+            self.locked_node_warden_metadata
+                .insert((target, name.clone()));
+            match target {
+                NodeWardenMetadataTarget::Component => {
+                    Runtime::global_component().lock_metadata(name)
+                }
+                NodeWardenMetadataTarget::OwnerBadge => self
+                    .node_warden_owner_badge_resource_manager
+                    .lock_metadata(name),
+                NodeWardenMetadataTarget::AccessKeyBadge => {
+                    self.access_key_badge_resource_manager.lock_metadata(name)
+                }
+            }
+        }
+
+        /// Create a proxy for the given validator, whose methods mirror those of the
+        /// native `Validator` component. Instead of taking a proof argument, the proxy
+        /// checks the auth zone of the caller for the badge it is bound to, so existing
+        /// tooling can target it as if it was the validator.
+        ///
+        /// A proxy bound to an access key badge applies all of its checks, like its
+        /// permissions, validator scope and second factor. Creating a new proxy for a
+        /// validator and badge replaces the previous one, which stops working.
+        ///
+        /// * `validator_address`: The validator to create the proxy for.
+        /// * `access_key_badge`: The access key badge the proxy accepts, which may be an
+        ///   honoured one, or `None` for the NodeWarden owner badge.
+        ///
+        /// # Returns
+        ///
+        /// The new proxy component.
+        pub fn create_validator_proxy(
+            &mut self,
+            validator_address: ComponentAddress,
+            access_key_badge: Option<NonFungibleGlobalId>,
+        ) -> Global<ValidatorProxy> {
+            self.validator_owner_badge_id(validator_address);
+            if let Some(access_key_badge) = &access_key_badge {
+                self.access_key_badge_local_id(access_key_badge);
+            }
+            let validator_proxy = Blueprint::<ValidatorProxy>::instantiate(
+                Runtime::global_address(),
+                validator_address,
+            );
+            let validator_proxy_key =
+                (validator_address, access_key_badge.clone());
+            if let Some(previous_validator_proxy) =
+                self.validator_proxy_addresses.remove(&validator_proxy_key)
+            {
+                self.validator_proxies.remove(&previous_validator_proxy);
+            }
+            self.validator_proxy_addresses
+                .insert(validator_proxy_key, validator_proxy.address());
+            self.validator_proxies.insert(
+                validator_proxy.address(),
+                ValidatorProxyBinding {
+                    validator_address,
+                    access_key_badge,
+                },
+            );
+            validator_proxy
+        }
+
+        /// Accept a migration from another NodeWarden component, which its owner then
+        /// starts using `migrate`. Both steps are meant to be in a single transaction.
+        ///
+        /// * `source`: The NodeWarden component to migrate from.
+        pub fn accept_migration(&mut self, source: ComponentAddress) {
+            self.migration_source = Some(source);
+        }
+
+        /// Migrate to a new NodeWarden component, which must have accepted the migration
+        /// using `accept_migration`. The owner badges of all validators are handed over,
+        /// along with the state of the given access key badges.
+        ///
+        /// Funds in custody and the treasury are not moved. The owner can withdraw them
+        /// and deposit them into the new component in the same transaction.
+        ///
+        /// * `new_node_warden`: The NodeWarden component to migrate to.
+        /// * `access_key_badge_local_ids`: The access key badges to carry over.
+        /// * `mode`: Whether the new component honours the old access key badges,
+        ///   or re-mints them to their holders.
+        pub fn migrate(
+            &mut self,
+            new_node_warden: Global<NodeWarden>,
+            access_key_badge_local_ids: Vec<NonFungibleLocalId>,
+            mode: MigrationMode,
+        ) {
+            // The validators are no longer managed by this component.
+            let validator_owner_badge_ids: IndexSet<NonFungibleLocalId> = self
+                .validators
+                .drain(..)
+                .map(|(_, non_fungible_id)| non_fungible_id)
+                .collect();
+            let validator_owner_badges = self
+                .validator_owner_badge
+                .take_non_fungibles(&validator_owner_badge_ids);
+
+            let access_key_badges = access_key_badge_local_ids
+                .iter()
+                .map(|local_id| self.export_access_key_badge(local_id))
+                .collect();
+
+            new_node_warden.import_migration(
+                validator_owner_badges,
+                self.access_key_badge_resource_manager.address(),
+                access_key_badges,
+                mode,
+            );
+        }
+
+        /// Restore the configuration of a snapshot taken using `export_configuration`:
+        /// the metadata and its locks, metadata schemas, compound policy and delegable
+        /// roles, and all access key badges with their treasury allowance and issuance quota.
+        ///
+        /// The validators of the snapshot are not restored, as their owner badges have
+        /// to be deposited using `deposit_validator_owner_badge`.
+        ///
+        /// * `snapshot`: The snapshot to restore.
+        /// * `recipient`: The account to deposit the re-minted access key badges into,
+        ///   for the owner to hand out.
+        pub fn import_snapshot(
+            &mut self,
+            snapshot: NodeWardenSnapshot,
+            mut recipient: Global<Account>,
+        ) {
+            for (target, name, metadata) in snapshot.metadata {
+                self.set_node_warden_metadata(target, name, metadata);
+            }
+            for (target, name) in snapshot.locked_metadata {
+                self.lock_node_warden_metadata(target, name);
+            }
+            for (name, schema) in snapshot.metadata_schemas {
+                self.metadata_schemas.insert(name, schema);
+            }
+            self.compound_policy = snapshot.compound_policy;
+            self.delegable_roles = snapshot.delegable_roles;
+
+            let mut access_key_badges = NonFungibleBucket::new(
+                self.access_key_badge_resource_manager.address(),
+            );
+            for (_, access_key_badge) in
+                self.import_access_key_badges(snapshot.access_key_badges)
+            {
+                access_key_badges.put(access_key_badge);
+            }
+            recipient.try_deposit_or_abort(access_key_badges.into(), None);
+        }
+
+        // ##############################################################
+        // ##### Methods that correspond to the Validator component #####
+        // ##############################################################
+
+        /// Stakes an amount of XRD to the Validator in exchange for Stake Units.
+        ///
+        /// This method is different from the regular `stake` method, in the
         /// fact that it will go through even if the validator does NOT
         /// accept delegated stake.
         ///
-        /// * `proof` - The proof of authorization.
-        /// * `stake` - The amount of XRD to stake.
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `stake` - The amount of XRD to stake.
+        ///
+        /// # Returns
+        ///
+        /// A bucket containing the Validator’s Stake Unit resource
+        ///
+        /// This function is a thin wrapper of the `stake_as_owner` method on the `Validator` component.
+        pub fn stake_as_owner(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            stake: FungibleBucket,
+        ) -> FungibleBucket {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::StakeAsOwner),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.stake_as_owner(stake),
+            )
+        }
+
+        /// Registers the Validator to be available to validate and propose transactions in Consensus
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        ///
+        /// This function is a thin wrapper of the `register` method on the `Validator` component.
+        pub fn register(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::Register),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.register(),
+            )
+        }
+
+        /// Unregisters the validator.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        ///
+        /// This function is a thin wrapper of the `unregister` method on the `Validator` component.
+        pub fn unregister(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::Unregister),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.unregister(),
+            )
+        }
+
+        /// Updates the public key of the Validator.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `key` - The public key to replace the Validator’s Consensus public key with.
+        ///
+        /// This function is a thin wrapper of the `update_key` method on the `Validator` component.
+        pub fn update_key(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            key: Secp256k1PublicKey,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::UpdateKey),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.update_key(key),
+            )
+        }
+
+        /// Changes the fee for the validator.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `new_fee_factor` - A decimal >= 0.0 and <= 1.0 representing the new fee fraction.
+        ///
+        /// This function is a thin wrapper of the `update_fee` method on the `Validator` component.
+        pub fn update_fee(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            new_fee_factor: Decimal,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::UpdateFee),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.update_fee(new_fee_factor),
+            )
+        }
+
+        /// Locks Stake Units held in custody by this component in an internal “delayed withdrawal”
+        /// vault (as a way of showing the Owner’s commitment to running the Validator).
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `amount` - The amount of custodied Stake Units to lock.
+        ///
+        /// This function wraps the `lock_owner_stake_units` method on the `Validator` component,
+        /// drawing the Stake Units from the custody vault instead of taking a bucket.
+        pub fn lock_owner_stake_units(
+            &mut self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            amount: Decimal,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::LockOwnerStakeUnits,
+                ),
+            );
+            let stake_unit_bucket =
+                self.owner_stake_unit_vault(validator_address).take(amount);
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.lock_owner_stake_units(stake_unit_bucket),
+            )
+        }
+
+        /// Begins the process of unlocking the Owner’s Stake Units.
+        ///
+        /// The requested amount of Stake Units (if available) will be ready for
+        /// withdrawal after the Network-configured number of Epochs is reached.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `requested_stake_unit_amount` - The amount of Stake Units to start unlocking.
+        ///
+        /// This function is a thin wrapper of the `start_unlock_owner_stake_units` method on the `Validator` component.
+        pub fn start_unlock_owner_stake_units(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            requested_stake_unit_amount: Decimal,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::StartUnlockOwnerStakeUnits,
+                ),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    validator.start_unlock_owner_stake_units(
+                        requested_stake_unit_amount,
+                    )
+                },
+            )
+        }
+
+        /// Finishes the process of unlocking the Owner’s Stake Units by withdrawing all the
+        /// pending amounts which have reached their target Epoch and
+        /// thus are already available - potentially none.
+        ///
+        /// The unlocked Stake Units are returned to the custody vault of this component.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        ///
+        /// This function wraps the `finish_unlock_owner_stake_units` method on the `Validator` component.
+        pub fn finish_unlock_owner_stake_units(
+            &mut self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::FinishUnlockOwnerStakeUnits,
+                ),
+            );
+            let stake_unit_bucket = self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| validator.finish_unlock_owner_stake_units(),
+            );
+            self.owner_stake_unit_vault(validator_address)
+                .put(stake_unit_bucket);
+        }
+
+        /// Updates the flag deciding whether the Validator should accept delegated stake.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `accept_delegated_stake` - Whether to accept delegated stake.
+        ///
+        /// This function is a thin wrapper of the `update_accept_delegated_stake` method on the `Validator` component.
+        pub fn update_accept_delegated_stake(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            accept_delegated_stake: bool,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::UpdateAcceptDelegatedStake,
+                ),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    validator
+                        .update_accept_delegated_stake(accept_delegated_stake)
+                },
+            )
+        }
+
+        /// Signals on ledger what protocol version to potentially change to. Used by Consensus to coordinate protocol updates.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `protocol_version_name` - The protocol version to signal readiness for.
+        ///
+        /// This function is a thin wrapper of the `signal_protocol_update_readiness` method on the `Validator` component.
+        pub fn signal_protocol_update_readiness(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            protocol_version_name: String,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::SignalProtocolUpdateReadiness,
+                ),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    validator
+                        .signal_protocol_update_readiness(protocol_version_name)
+                },
+            )
+        }
+
+        // #########################################################
+        // ##### Additional methods ################################
+        // #########################################################
+
+        /// Set metadata for the validator component
+        ///
+        /// Values set through an access key badge must follow the schema
+        /// the owner has set for the field, if any.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `name` - The name of the metadata field.
+        /// * `metadata` - The metadata value to set.
+        ///
+        pub fn set_metadata(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            name: String,
+            metadata: MetadataValue,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::SetMetadata(vec![(
+                    name.clone(),
+                    metadata.clone(),
+                )]),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| set_metadata_value(validator, name, metadata),
+            );
+        }
+
+        /// Remove metadata from the validator component
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `name` - The name of the metadata field.
+        ///
+        pub fn remove_metadata(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            name: String,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::RemoveMetadata),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    validator.remove_metadata(name);
+                },
+            );
+        }
+
+        /// Set several pieces of metadata on the validator component at once,
+        /// under a single authorization of the validator owner badge.
+        ///
+        /// Values set through an access key badge must follow the schema
+        /// the owner has set for their field, if any.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `entries` - The names of the metadata fields and the values to set.
+        ///
+        pub fn set_metadata_batch(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            entries: Vec<(String, MetadataValue)>,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::SetMetadata(entries.clone()),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    for (name, metadata) in entries {
+                        set_metadata_value(validator, name, metadata);
+                    }
+                },
+            );
+        }
+
+        /// Remove several pieces of metadata from the validator component at once,
+        /// under a single authorization of the validator owner badge.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `names` - The names of the metadata fields.
+        ///
+        pub fn remove_metadata_batch(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            names: Vec<String>,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::RemoveMetadata),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    for name in names {
+                        validator.remove_metadata(name);
+                    }
+                },
+            );
+        }
+
+        /// Lock a piece of metadata on the validator component
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `name` - The name of the metadata field.
+        ///
+        pub fn lock_metadata(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            name: String,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::LockMetadata),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    validator.lock_metadata(name);
+                },
+            );
+        }
+
+        /// Reads a role of the validator component.
+        ///
+        /// Access key badges can only read the roles the owner has made delegable.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `role` - The role to read.
+        ///
+        /// # Returns
+        ///
+        /// The access rule of the role, if it is set.
+        pub fn get_validator_role(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            role: ValidatorRole,
+        ) -> Option<AccessRule> {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::GetValidatorRole(role.clone()),
+            );
+            let validator: Global<Validator> = validator_address.into();
+            match role {
+                ValidatorRole::Owner => Some(validator.get_owner_role().rule),
+                ValidatorRole::Main(role_key) => validator.get_role(&role_key),
+                ValidatorRole::Metadata(role_key) => {
+                    RoleAssignment::attached(validator_address.into())
+                        .get_metadata_role(&role_key)
+                }
+            }
+        }
+
+        /// Sets a role of the validator component.
+        ///
+        /// Access key badges can only set the roles the owner has made delegable.
+        ///
+        /// # Warning
+        /// Setting the owner role to anything other than the validator owner badge
+        /// hands over control of the validator, including from this component.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `role` - The role to set.
+        /// * `rule` - The new access rule of the role.
+        pub fn set_validator_role(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            role: ValidatorRole,
+            rule: AccessRule,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::SetValidatorRole(role.clone()),
+            );
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| match role {
+                    ValidatorRole::Owner => validator.set_owner_role(rule),
+                    ValidatorRole::Main(role_key) => {
+                        validator.set_role(&role_key, rule)
+                    }
+                    ValidatorRole::Metadata(role_key) => {
+                        validator.set_metadata_role(&role_key, rule)
+                    }
+                },
+            )
+        }
+
+        /// Stakes XRD from the treasury of this component to the Validator as owner.
+        ///
+        /// Access key badges can only stake up to their treasury allowance, which is
+        /// reduced by the staked amount. The owner badge is not limited by an allowance.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `amount` - The amount of XRD to stake from the treasury.
+        /// * `lock` - Whether to lock the resulting Stake Units in the Validator right away.
+        ///   Otherwise, they are kept in the custody vault of this component.
+        ///
+        /// This function wraps the `stake_as_owner` method on the `Validator` component.
+        pub fn stake_from_treasury(
+            &mut self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            amount: Decimal,
+            lock: bool,
+        ) {
+            let access_key_badge_local_id = self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::StakeFromTreasury(amount),
+            );
+
+            // Access key badges draw down their allowance
+            if let Some(access_key_badge_local_id) = access_key_badge_local_id {
+                let allowance =
+                    self.treasury_allowance(&access_key_badge_local_id);
+                self.treasury_allowances
+                    .insert(access_key_badge_local_id, allowance - amount);
+            }
+
+            let xrd = self.xrd_treasury.take(amount);
+            let stake_unit_bucket = self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |validator| {
+                    let stake_unit_bucket = validator.stake_as_owner(xrd);
+                    if lock {
+                        validator.lock_owner_stake_units(stake_unit_bucket);
+                        None
+                    } else {
+                        Some(stake_unit_bucket)
+                    }
+                },
+            );
+            if let Some(stake_unit_bucket) = stake_unit_bucket {
+                self.owner_stake_unit_vault(validator_address)
+                    .put(stake_unit_bucket);
+            }
+        }
+
+        /// Compounds the owner rewards of the Validator, following the owner-defined [`CompoundPolicy`].
+        ///
+        /// The cycle finishes all ready unlocks of owner stake units. If the policy says to
+        /// restake, the unlocked stake units are unstaked into a claim NFT held in custody.
+        /// The XRD of the claim NFTs of earlier cycles that are ready is claimed and staked
+        /// as owner again, even if the policy has stopped restaking since. Claim NFTs of
+        /// `unstake` are left alone. Finally, the resulting stake units are either locked
+        /// again, or kept in custody.
+        ///
+        /// Every amount involved is reported in an [`OwnerRewardsCompoundedEvent`].
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        pub fn compound_owner_rewards(
+            &mut self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::CompoundOwnerRewards,
+                ),
+            );
+            let restake = self.compound_policy.restake;
+            let relock = self.compound_policy.relock;
+
+            // Claim NFTs that are ready to be claimed, from earlier cycles
+            let ready_claim_nfts = self
+                .compound_claim_nft_custody(validator_address)
+                .take_ready();
+
+            let mut event = OwnerRewardsCompoundedEvent {
+                validator_address,
+                unlocked_stake_units: Decimal::ZERO,
+                unstaked_stake_units: Decimal::ZERO,
+                restaked_xrd: Decimal::ZERO,
+                restaked_stake_units: Decimal::ZERO,
+                relocked_stake_units: Decimal::ZERO,
+                custodied_stake_units: Decimal::ZERO,
+            };
+            let (stake_unit_bucket, claim_nft_bucket) = self
+                .do_with_validator_owner_badge_proof(
+                    validator_address,
+                    |validator| {
+                        let mut stake_unit_bucket =
+                            validator.finish_unlock_owner_stake_units();
+                        event.unlocked_stake_units = stake_unit_bucket.amount();
+
+                        let mut claim_nft_bucket = None;
+                        if restake && !stake_unit_bucket.is_empty() {
+                            event.unstaked_stake_units =
+                                stake_unit_bucket.amount();
+                            claim_nft_bucket =
+                                Some(validator.unstake(stake_unit_bucket));
+                            stake_unit_bucket = FungibleBucket::new(
+                                Self::stake_unit_resource(validator_address),
+                            );
+                        }
+                        if let Some(ready_claim_nfts) = ready_claim_nfts {
+                            let xrd = validator.claim_xrd(ready_claim_nfts);
+                            event.restaked_xrd = xrd.amount();
+                            let restaked = validator.stake_as_owner(xrd);
+                            event.restaked_stake_units = restaked.amount();
+                            stake_unit_bucket.put(restaked);
+                        }
+
+                        if relock && !stake_unit_bucket.is_empty() {
+                            event.relocked_stake_units =
+                                stake_unit_bucket.amount();
+                            validator.lock_owner_stake_units(stake_unit_bucket);
+                            (None, claim_nft_bucket)
+                        } else {
+                            (Some(stake_unit_bucket), claim_nft_bucket)
+                        }
+                    },
+                );
+
+            if let Some(claim_nft_bucket) = claim_nft_bucket {
+                self.compound_claim_nft_custody(validator_address)
+                    .put(claim_nft_bucket);
+            }
+            if let Some(stake_unit_bucket) = stake_unit_bucket {
+                event.custodied_stake_units = stake_unit_bucket.amount();
+                self.owner_stake_unit_vault(validator_address)
+                    .put(stake_unit_bucket);
+            }
+
+            Runtime::emit_event(event);
+        }
+
+        /// Unstakes owner Stake Units held in custody by this component.
+        ///
+        /// The claim NFT the Validator hands out is kept in custody as well, until it
+        /// is claimed using `claim_xrd` or withdrawn by the owner.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `amount` - The amount of custodied Stake Units to unstake.
+        ///
+        /// This function wraps the `unstake` method on the `Validator` component.
+        pub fn unstake(
+            &mut self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            amount: Decimal,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::Unstake),
+            );
+            let stake_unit_bucket =
+                self.owner_stake_unit_vault(validator_address).take(amount);
+            let mut validator: Global<Validator> = validator_address.into();
+            let claim_nft_bucket = validator.unstake(stake_unit_bucket);
+            self.claim_nft_custody(validator_address)
+                .put(claim_nft_bucket);
+        }
+
+        /// Claims the XRD of all custodied claim NFTs of the Validator that are ready
+        /// to be claimed - potentially none. Claim NFTs `compound_owner_rewards` is
+        /// going to restake are left alone.
+        ///
+        /// The claimed XRD is added to the treasury of this component.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        ///
+        /// This function wraps the `claim_xrd` method on the `Validator` component.
+        pub fn claim_xrd(
+            &mut self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+        ) {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(PermissionType::ClaimXrd),
+            );
+            let ready_claim_nfts =
+                self.claim_nft_custody(validator_address).take_ready();
+            if let Some(ready_claim_nfts) = ready_claim_nfts {
+                let mut validator: Global<Validator> = validator_address.into();
+                let xrd = validator.claim_xrd(ready_claim_nfts);
+                self.xrd_treasury.put(xrd);
+            }
+        }
+
+        /// Calls any method on the Validator with authorization of its owner badge.
+        ///
+        /// This allows using new features of the `Validator` blueprint without a dedicated
+        /// wrapper. Access key badges can only call the methods on their allowlist, which the
+        /// owner manages using `update_access_key_badge_validator_methods`.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator to act on.
+        /// * `method_name` - The name of the method on the `Validator` component.
+        /// * `args` - The arguments of the method, as a tuple.
+        ///
+        /// # Returns
+        ///
+        /// The return value of the method.
+        pub fn call_validator_method(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            method_name: String,
+            args: ScryptoValue,
+        ) -> ScryptoValue {
+            // Access key badges can only call the methods on their allowlist
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::CallValidatorMethod(method_name.clone()),
+            );
+
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |_validator| {
+                    call_method_raw(validator_address, &method_name, args)
+                },
+            )
+        }
+
+        /// Calls a method on another component with authorization of the owner badge of the
+        /// given validator, without ever exporting a proof of the badge.
+        ///
+        /// Access key badges can only call the methods on their allowlist, which the
+        /// owner manages using `update_access_key_badge_component_methods`.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator whose owner badge authorizes the call.
+        /// * `component_method` - The component and the name of the method to call on it.
+        /// * `args` - The arguments of the method, as a tuple.
+        ///
+        /// # Returns
+        ///
+        /// The return value of the method.
+        pub fn call_component_method(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            component_method: ComponentMethod,
+            args: ScryptoValue,
+        ) -> ScryptoValue {
+            // Access key badges can only call the methods on their allowlist
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::CallComponentMethod(component_method.clone()),
+            );
+
+            self.do_with_validator_owner_badge_proof(
+                validator_address,
+                |_validator| {
+                    call_method_raw(
+                        component_method.component_address,
+                        &component_method.method_name,
+                        args,
+                    )
+                },
+            )
+        }
+
+        /// Create a proof of the owner badge of the given validator.
+        /// The proof can be used to call any of the validator component's methods.
+        ///
+        /// * `proof` - The proof of authorization.
+        /// * `validator_address` - The validator whose owner badge to create a proof of.
+        ///
+        /// # Warning
+        /// By enabling the permission for this method, you allow the caller to create a proof of the validator owner badge.
+        /// This essentially gives the caller the ability to perform any action that requires the validator owner badge, including
+        /// things like changing fees and withdrawing stake units from the validator's vault.
+        pub fn create_validator_owner_badge_proof(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+        ) -> NonFungibleProof {
+            self.check_proof(
+                proof,
+                validator_address,
+                &ProposedAction::Permission(
+                    PermissionType::CreateValidatorOwnerBadgeProof,
+                ),
+            );
+            let non_fungible_id =
+                self.validator_owner_badge_id(validator_address);
+            self.validator_owner_badge
+                .create_proof_of_non_fungibles(&indexset!(non_fungible_id))
+        }
+
+        /// Create a proof of the owner badge of the validator of a validator proxy, for
+        /// the proxy itself, which must be the global caller. The proxy must have checked
+        /// the auth zone of its own caller against `get_access_rule`. The access key badge it is bound to, if any, must
+        /// pass all checks of the permission.
+        ///
+        /// * `validator_proxy` - The calling validator proxy.
+        /// * `permission_type` - The permission the action of the proxy requires.
+        pub fn create_validator_proxy_proof(
+            &self,
+            validator_proxy: ComponentAddress,
+            permission_type: PermissionType,
+        ) -> NonFungibleProof {
+            // Only the proxy itself may ask, as only it checks the auth zone of its caller
+            Runtime::assert_access_rule(rule!(require(global_caller(
+                validator_proxy
+            ))));
+            let ValidatorProxyBinding {
+                validator_address,
+                access_key_badge,
+            } = self.validator_proxy_binding(validator_proxy);
+
+            if let Some(access_key_badge) = access_key_badge {
+                let access_key_badge_local_id =
+                    self.access_key_badge_local_id(&access_key_badge);
+                let access_key_badge_data = self
+                    .access_key_badge_resource_manager
+                    .get_non_fungible_data::<AccessKeyBadgeData>(
+                        &access_key_badge_local_id,
+                    );
+                self.evaluate_action(
+                    &access_key_badge_local_id,
+                    &access_key_badge_data,
+                    validator_address,
+                    &ProposedAction::Permission(permission_type),
+                )
+                .unwrap_or_else(|error| error.raise());
+                Runtime::emit_event(AccessKeyUsedEvent {
+                    access_key_badge_local_id,
+                    validator_address,
+                    permission_type,
+                });
+            }
+
+            let non_fungible_id =
+                self.validator_owner_badge_id(validator_address);
+            self.validator_owner_badge
+                .create_proof_of_non_fungibles(&indexset!(non_fungible_id))
+        }
+
+        /// Take the owner stake units a validator proxy has unlocked into custody, so
+        /// its callers never possess them. Only the proxy itself can call this.
+        ///
+        /// * `validator_proxy` - The calling validator proxy.
+        /// * `stake_units` - A bucket of stake units of the validator of the proxy.
+        pub fn deposit_validator_proxy_stake_units(
+            &mut self,
+            validator_proxy: ComponentAddress,
+            stake_units: FungibleBucket,
+        ) {
+            Runtime::assert_access_rule(rule!(require(global_caller(
+                validator_proxy
+            ))));
+            let validator_address = self
+                .validator_proxy_binding(validator_proxy)
+                .validator_address;
+            if stake_units.resource_address()
+                != Self::stake_unit_resource(validator_address)
+            {
+                NodeWardenError::NotStakeUnitsOfValidator(validator_address)
+                    .raise();
+            }
+            self.owner_stake_unit_vault(validator_address)
+                .put(stake_units);
+        }
+
+        /// Receive a migration from the NodeWarden component the owner has accepted it
+        /// from. The validator owner badges are deposited, and the access key badges are
+        /// imported with their permissions, allowlists, treasury allowance and issuance quota.
+        ///
+        /// * `validator_owner_badges` - The owner badges of the migrated validators.
+        /// * `source_access_key_resource` - The access key badge resource of the old component.
+        /// * `access_key_badges` - The state of the access key badges to import.
+        /// * `mode` - Whether to honour the old access key badges, or re-mint them to their holders.
+        pub fn import_migration(
+            &mut self,
+            validator_owner_badges: NonFungibleBucket,
+            source_access_key_resource: ResourceAddress,
+            access_key_badges: Vec<AccessKeyBadgeExport>,
+            mode: MigrationMode,
+        ) {
+            let source = self.migration_source.take().unwrap_or_else(|| {
+                NodeWardenError::MigrationNotAccepted.raise()
+            });
+            Runtime::assert_access_rule(rule!(require(global_caller(source))));
+
+            self.deposit_validator_owner_badge(validator_owner_badges);
+
+            if mode == MigrationMode::Honour {
+                if self.honoured_access_key_resource.is_some_and(|resource| {
+                    resource != source_access_key_resource
+                }) {
+                    NodeWardenError::OtherAccessKeysHonoured.raise();
+                }
+                self.honoured_access_key_resource =
+                    Some(source_access_key_resource);
+            }
+
+            for (local_id, access_key_badge) in
+                self.import_access_key_badges(access_key_badges)
+            {
+                match mode {
+                    MigrationMode::Honour => {
+                        self.honoured_access_keys.insert(
+                            local_id,
+                            access_key_badge.non_fungible_local_id(),
+                        );
+                        self.honoured_access_key_vault.put(access_key_badge);
+                    }
+                    MigrationMode::Remint => {
+                        let mut holder: Global<Account> = access_key_badge
+                            .non_fungible::<AccessKeyBadgeData>()
+                            .data()
+                            .issued_to
+                            .unwrap_or_else(|| {
+                                NodeWardenError::UnknownAccessKeyHolder(
+                                    local_id,
+                                )
+                                .raise()
+                            })
+                            .into();
+                        holder.try_deposit_or_abort(
+                            access_key_badge.into(),
+                            None,
+                        );
+                    }
+                }
+            }
+        }
+
+        /// Issue a new access key badge as an access key holder, and deposit it
+        /// into the account of the recipient.
+        ///
+        /// The new access key badge can not have more permissions or a wider validator
+        /// scope than the issuing one, and counts against the issuance quota the owner
+        /// has set for the issuing access key badge. It records its issuer.
+        ///
+        /// * `proof` - The proof of the issuing access key badge.
+        /// * `permissions` - The permissions of the new access key badge.
+        /// * `validator_scope` - The validators the new access key badge applies to.
+        /// * `label` - The display data of the new access key badge.
+        /// * `recipient` - The account to deposit the new access key badge into.
+        ///
+        /// # Returns
+        ///
+        /// The local ID of the new access key badge.
+        pub fn issue_access_key_badge(
+            &mut self,
+            proof: NonFungibleProof,
+            permissions: AccessKeyPermissions,
+            validator_scope: ValidatorScope,
+            label: AccessKeyLabel,
+            mut recipient: Global<Account>,
+        ) -> NonFungibleLocalId {
+            let issuer = proof
+                .check_with_message(
+                    self.access_key_badge_resource_manager.address(),
+                    NodeWardenError::NotAnAccessKeyBadge.to_string(),
+                )
+                .non_fungible::<AccessKeyBadgeData>();
+            let issuer_data = issuer.data();
+            let issuer_local_id = issuer.local_id().clone();
+
+            if issuer_data.compromised {
+                NodeWardenError::AccessKeyCompromised.raise();
+            }
+            assert_required_signer(&issuer_data);
+            if !issuer_data
+                .permissions
+                .is_allowed(&PermissionType::IssueAccessKeys)
+            {
+                NodeWardenError::PermissionDenied(
+                    PermissionType::IssueAccessKeys,
+                )
+                .raise();
+            }
+            if !permissions.is_subset_of(&issuer_data.permissions) {
+                NodeWardenError::IssuedPermissionsExceedIssuer.raise();
+            }
+            if !validator_scope.is_subset_of(&issuer_data.validator_scope) {
+                NodeWardenError::IssuedValidatorScopeExceedsIssuer.raise();
+            }
+
+            let mut quota = self
+                .issuance_quotas
+                .get_mut(&issuer_local_id)
+                .unwrap_or_else(|| {
+                    NodeWardenError::IssuanceQuotaExceeded.raise()
+                });
+            if *quota == 0 {
+                NodeWardenError::IssuanceQuotaExceeded.raise();
+            }
+            *quota -= 1;
+            drop(quota);
+
+            let access_key_badge_data = AccessKeyBadgeData {
+                name: label.name,
+                description: label.description,
+                key_image_url: label.key_image_url,
+                issued_to: Some(recipient.address()),
+                issued_at: Clock::current_time_rounded_to_seconds(),
+                issuer: Some(issuer_local_id),
+                node_warden_component_address: Runtime::global_address(),
+                permissions,
+                validator_scope,
+                allowed_validator_methods: IndexSet::new(),
+                allowed_component_methods: IndexSet::new(),
+                compromised: false,
+                required_signer: None,
+            };
+            let access_key_badge = self
+                .access_key_badge_resource_manager
+                .mint_ruid_non_fungible(access_key_badge_data);
+            let local_id = access_key_badge.non_fungible_local_id();
+            self.record_access_key_badge(local_id.clone());
+            recipient.try_deposit_or_abort(access_key_badge.into(), None);
+            local_id
+        }
+
+        /// Recall and burn an access key badge the given access key badge has issued.
+        /// The issuing access key badge needs the `issue_access_keys` permission.
+        ///
+        /// * `proof` - The proof of the issuing access key badge.
+        /// * `vault_address` - The vault holding the access key badge to recall.
+        /// * `id` - The local ID of the access key badge to recall.
+        pub fn recall_and_burn_issued_access_key_badge(
+            &self,
+            proof: NonFungibleProof,
+            vault_address: InternalAddress,
+            id: NonFungibleLocalId,
+        ) {
+            let issuer = proof
+                .check_with_message(
+                    self.access_key_badge_resource_manager.address(),
+                    NodeWardenError::NotAnAccessKeyBadge.to_string(),
+                )
+                .non_fungible::<AccessKeyBadgeData>();
+            let issuer_data = issuer.data();
+            let issuer_local_id = issuer.local_id().clone();
+
+            if issuer_data.compromised {
+                NodeWardenError::AccessKeyCompromised.raise();
+            }
+            assert_required_signer(&issuer_data);
+            if !issuer_data
+                .permissions
+                .is_allowed(&PermissionType::IssueAccessKeys)
+            {
+                NodeWardenError::PermissionDenied(
+                    PermissionType::IssueAccessKeys,
+                )
+                .raise();
+            }
+
+            let access_key_badge_data =
+                self.access_key_badge_resource_manager
+                    .get_non_fungible_data::<AccessKeyBadgeData>(&id);
+            // Keys issued before the issuer was re-keyed belong to its replacement.
+            let mut issuer = access_key_badge_data.issuer;
+            while let Some(successor) = issuer
+                .as_ref()
+                .and_then(|issuer| self.successors.get(issuer))
+            {
+                issuer = Some(successor.clone());
+            }
+            if issuer != Some(issuer_local_id) {
+                NodeWardenError::NotIssuedByAccessKey.raise();
+            }
+            self.recall_access_key_badge(vault_address, id).burn();
+        }
+
+        /// Renounce permissions of your own access key badge. Permissions can
+        /// only be given up this way, never gained.
+        ///
+        /// * `proof` - The proof of the access key badge.
+        /// * `permissions` - The permissions to renounce. These are snake-case strings
+        ///   resembling the permissions as defined in the [`AccessKeyPermissions`] struct.
+        pub fn renounce_access_key_badge_permissions(
+            &self,
+            proof: NonFungibleProof,
+            permissions: Vec<String>,
+        ) {
+            let local_id = self.access_key_badge_local_id(
+                &Self::proven_access_key_badge(proof),
+            );
+            let mut access_key_badge_data = self
+                .access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(&local_id);
+
+            for permission in permissions {
+                let permission_type = PermissionType::from_str(&permission)
+                    .unwrap_or_else(|_| {
+                        NodeWardenError::InvalidPermission(permission.clone())
+                            .raise()
+                    });
+                access_key_badge_data
+                    .permissions
+                    .update_permission(permission_type, false);
+            }
+
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(
+                    &local_id,
+                    "permissions",
+                    access_key_badge_data.permissions,
+                );
+        }
+
+        /// Report your own access key badge compromised, e.g. after losing the
+        /// device holding it. This disables the access key badge immediately and
+        /// permanently, and emits an [`AccessKeyCompromisedEvent`] to alert the owner.
+        ///
+        /// * `proof` - The proof of the access key badge.
+        pub fn report_access_key_badge_compromised(
+            &self,
+            proof: NonFungibleProof,
+        ) {
+            let local_id = self.access_key_badge_local_id(
+                &Self::proven_access_key_badge(proof),
+            );
+
+            self.access_key_badge_resource_manager
+                .update_non_fungible_data(&local_id, "compromised", true);
+
+            Runtime::emit_event(AccessKeyCompromisedEvent {
+                access_key_badge_local_id: local_id,
+                reported_at: Clock::current_time_rounded_to_seconds(),
+            });
+        }
+
+        /// Request to move your own access key badge to another account, e.g. when
+        /// migrating to a new account. The move happens once the owner approves it
+        /// using `approve_access_key_badge_move`. A new request replaces a pending one.
+        /// Requests for honoured access key badges are kept under the local ID of
+        /// their imported counterparts.
+        ///
+        /// * `proof` - The proof of the access key badge.
+        /// * `vault_address` - The vault currently holding the access key badge.
+        /// * `target_account` - The account to move the access key badge to.
+        pub fn request_access_key_badge_move(
+            &mut self,
+            proof: NonFungibleProof,
+            vault_address: InternalAddress,
+            target_account: Global<Account>,
+        ) {
+            let access_key_badge = Self::proven_access_key_badge(proof);
+            let local_id = self.access_key_badge_local_id(&access_key_badge);
+            let access_key_badge_data =
+                self.access_key_badge_resource_manager
+                    .get_non_fungible_data::<AccessKeyBadgeData>(&local_id);
+            if access_key_badge_data.compromised {
+                NodeWardenError::AccessKeyCompromised.raise();
+            }
+            assert_required_signer(&access_key_badge_data);
+
+            let honoured_access_key_badge = (access_key_badge
+                .resource_address()
+                != self.access_key_badge_resource_manager.address())
+            .then(|| access_key_badge.local_id().clone());
+            self.move_requests.insert(
+                local_id,
+                AccessKeyMoveRequest {
+                    vault_address,
+                    target_account: target_account.address(),
+                    honoured_access_key_badge,
+                },
+            );
+        }
+
+        // #########################################################
+        // ##### View methods ######################################
+        // #########################################################
+
+        /// Returns the addresses of the validators this component manages.
+        pub fn get_validator_addresses(&self) -> Vec<ComponentAddress> {
+            self.validators.keys().copied().collect()
+        }
+
+        /// Returns whether this component holds the owner badge of the given validator.
+        ///
+        /// * `validator_address` - The validator to look up.
+        pub fn has_validator_owner_badge(
+            &self,
+            validator_address: ComponentAddress,
+        ) -> bool {
+            self.validators.contains_key(&validator_address)
+        }
+
+        /// Returns the permissions of an access key badge.
+        ///
+        /// * `access_key_badge_local_id` - The local ID of the access key badge.
+        pub fn get_access_key_permissions(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+        ) -> AccessKeyPermissions {
+            self.access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(
+                    &access_key_badge_local_id,
+                )
+                .permissions
+        }
+
+        /// Returns the resource address of the NodeWarden owner badge.
+        pub fn get_owner_badge_resource(&self) -> ResourceAddress {
+            self.node_warden_owner_badge_resource_manager.address()
+        }
+
+        /// Returns the resource address of the access key badges.
+        pub fn get_access_key_resource(&self) -> ResourceAddress {
+            self.access_key_badge_resource_manager.address()
+        }
+
+        /// Returns a summary of the configuration of this component.
+        pub fn get_configuration(&self) -> NodeWardenConfiguration {
+            NodeWardenConfiguration {
+                owner_badge_resource: self.get_owner_badge_resource(),
+                access_key_resource: self.get_access_key_resource(),
+                validators: self.validators.clone(),
+                xrd_treasury_amount: self.xrd_treasury.amount(),
+                compound_policy: self.compound_policy.clone(),
+                delegable_roles: self.delegable_roles.clone(),
+            }
+        }
+
+        /// Returns a snapshot of the configuration of this component, which
+        /// `new_from_snapshot` can instantiate a fresh component from.
+        pub fn export_configuration(&self) -> NodeWardenSnapshot {
+            NodeWardenSnapshot {
+                validators: self.get_validator_addresses(),
+                owner_badge_resource: self.get_owner_badge_resource(),
+                access_key_resource: self.get_access_key_resource(),
+                metadata: self
+                    .node_warden_metadata
+                    .iter()
+                    .map(|((target, name), metadata)| {
+                        (*target, name.clone(), metadata.clone())
+                    })
+                    .collect(),
+                locked_metadata: self
+                    .locked_node_warden_metadata
+                    .iter()
+                    .cloned()
+                    .collect(),
+                metadata_schemas: self
+                    .metadata_schemas
+                    .iter()
+                    .map(|(name, schema)| (name.clone(), schema.clone()))
+                    .collect(),
+                compound_policy: self.compound_policy.clone(),
+                delegable_roles: self.delegable_roles.clone(),
+                access_key_badges: (0..self.access_key_badge_count)
+                    .filter_map(|index| {
+                        self.access_key_badges
+                            .get(&index)
+                            .map(|local_id| local_id.clone())
+                    })
+                    .filter(|local_id| {
+                        self.access_key_badge_resource_manager
+                            .non_fungible_exists(local_id)
+                    })
+                    .map(|local_id| self.export_access_key_badge(&local_id))
+                    .collect(),
+            }
+        }
+
+        /// Returns the permissions an access key badge effectively has. Holders of
+        /// `create_validator_owner_badge_proof` implicitly have every permission a
+        /// validator owner badge proof can do, and compromised access key badges
+        /// have no permissions at all.
+        ///
+        /// * `access_key_badge_local_id` - The local ID of the access key badge.
+        pub fn get_effective_permissions(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+        ) -> AccessKeyPermissions {
+            let access_key_badge_data = self
+                .access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(
+                &access_key_badge_local_id,
+            );
+            if access_key_badge_data.compromised {
+                return AccessKeyPermissions::default();
+            }
+            access_key_badge_data.permissions.effective()
+        }
+
+        /// Dry-runs the checks of a delegated method guarded by the given permission,
+        /// for an access key badge on a validator.
+        ///
+        /// The second factor of the access key badge is not checked, as it depends
+        /// on the signatures of the transaction. Permissions whose checks depend on
+        /// the call, like `set_metadata`, are rejected with `ProposedActionRequired`;
+        /// dry-run those using `can_perform`.
+        ///
+        /// * `access_key_badge_local_id` - The local ID of the access key badge.
+        /// * `validator_address` - The validator to act on, ignored for `issue_access_keys`.
+        /// * `permission_type` - The permission the delegated method requires.
+        ///
+        /// # Returns
+        ///
+        /// `Ok` if the call would pass, or the rule that blocks it.
+        pub fn can(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            validator_address: ComponentAddress,
+            permission_type: PermissionType,
+        ) -> Result<(), NodeWardenError> {
+            self.evaluate_action(
+                &access_key_badge_local_id,
+                &self
+                    .access_key_badge_resource_manager
+                    .get_non_fungible_data::<AccessKeyBadgeData>(
+                        &access_key_badge_local_id,
+                    ),
+                validator_address,
+                &ProposedAction::Permission(permission_type),
+            )
+        }
+
+        /// Dry-runs all checks of the delegated method of a proposed action, like
+        /// metadata schemas, delegable roles, treasury allowances and allowlists,
+        /// for an access key badge on a validator.
+        ///
+        /// The second factor of the access key badge is not checked, as it depends
+        /// on the signatures of the transaction.
+        ///
+        /// * `access_key_badge_local_id` - The local ID of the access key badge.
+        /// * `validator_address` - The validator to act on.
+        /// * `action` - The proposed action.
+        ///
+        /// # Returns
+        ///
+        /// `Ok` if the call would pass, or the rule that blocks it.
+        pub fn can_perform(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+            validator_address: ComponentAddress,
+            action: ProposedAction,
+        ) -> Result<(), NodeWardenError> {
+            self.evaluate_action(
+                &access_key_badge_local_id,
+                &self
+                    .access_key_badge_resource_manager
+                    .get_non_fungible_data::<AccessKeyBadgeData>(
+                        &access_key_badge_local_id,
+                    ),
+                validator_address,
+                &action,
+            )
+        }
+
+        /// Look up the replacement of a re-keyed access key badge.
+        ///
+        /// * `access_key_badge_local_id` - The local ID of the replaced access key badge.
+        ///
+        /// # Returns
+        ///
+        /// The local ID of the replacement, if the access key badge was re-keyed.
+        pub fn get_access_key_badge_successor(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+        ) -> Option<NonFungibleLocalId> {
+            self.successors
+                .get(&access_key_badge_local_id)
+                .map(|successor| successor.clone())
+        }
+
+        /// Returns the access rule the auth zone of the caller of a validator proxy must
+        /// satisfy: the badge the proxy is bound to, together with its second factor.
+        ///
+        /// * `validator_proxy` - The validator proxy.
+        pub fn get_access_rule(
+            &self,
+            validator_proxy: ComponentAddress,
+        ) -> AccessRule {
+            let access_key_badge = match self
+                .validator_proxy_binding(validator_proxy)
+                .access_key_badge
+            {
+                Some(access_key_badge) => access_key_badge,
+                None => {
+                    return rule!(require(
+                        self.node_warden_owner_badge_resource_manager.address()
+                    ))
+                }
+            };
+            let access_key_badge_data = self
+                .access_key_badge_resource_manager
+                .get_non_fungible_data::<AccessKeyBadgeData>(
+                &self.access_key_badge_local_id(&access_key_badge),
+            );
+            match access_key_badge_data.required_signer {
+                Some(required_signer) => rule!(require_all_of(vec![
+                    access_key_badge,
+                    NonFungibleGlobalId::from_public_key(&required_signer),
+                ])),
+                None => rule!(require(access_key_badge)),
+            }
+        }
+
+        /// Returns the validator proxy of the given validator and badge, if any.
+        ///
+        /// * `validator_address` - The validator to look up.
+        /// * `access_key_badge` - The access key badge the proxy accepts, or `None`
+        ///   for the NodeWarden owner badge.
+        pub fn get_validator_proxy(
+            &self,
+            validator_address: ComponentAddress,
+            access_key_badge: Option<NonFungibleGlobalId>,
+        ) -> Option<ComponentAddress> {
+            self.validator_proxy_addresses
+                .get(&(validator_address, access_key_badge))
+                .map(|validator_proxy| *validator_proxy)
+        }
+
+        /// Look up the imported access key badge an honoured access key badge of the
+        /// component migrated from stands for.
+        ///
+        /// * `access_key_badge_local_id` - The local ID of the old access key badge.
+        pub fn get_honoured_access_key_badge(
+            &self,
+            access_key_badge_local_id: NonFungibleLocalId,
+        ) -> Option<NonFungibleLocalId> {
+            self.honoured_access_keys
+                .get(&access_key_badge_local_id)
+                .map(|local_id| local_id.clone())
+        }
+
+        // #########################################################
+        // ##### Some non-public helpers ###########################
+        // #########################################################
+
+        /// Executes a closure with permission of the owner badge of the given validator.
+        ///
+        /// * `validator_address` - The validator whose owner badge should authorize the closure.
+        /// * `f` - The closure to execute with authorization of the validator owner badge.
+        ///     This closure takes a mutable reference to the `Validator` component, allows
+        ///     you to perform actions on the validator.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn do_with_validator_owner_badge_proof<F, O>(
+            &self,
+            validator_address: ComponentAddress,
+            f: F,
+        ) -> O
+        where
+            F: FnOnce(&mut Global<Validator>) -> O,
+        {
+            let non_fungible_id =
+                self.validator_owner_badge_id(validator_address);
+            let mut validator: Global<Validator> = validator_address.into();
+            // Only the badge of the targeted validator is used for authorization
+            self.validator_owner_badge.authorize_with_non_fungibles(
+                &indexset!(non_fungible_id),
+                || f(&mut validator),
+            )
+        }
+
+        /// Looks up the local ID of the owner badge of the given validator.
+        ///
+        /// # Panics
+        /// Panics if this component does not hold the owner badge of the validator.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn validator_owner_badge_id(
+            &self,
+            validator_address: ComponentAddress,
+        ) -> NonFungibleLocalId {
+            self.validators
+                .get(&validator_address)
+                .unwrap_or_else(|| {
+                    NodeWardenError::NoValidatorOwnerBadge(validator_address)
+                        .raise()
+                })
+                .clone()
+        }
+
+        /// Looks up what the given validator proxy is bound to.
+        ///
+        /// # Panics
+        /// Panics if the component is not a validator proxy of this component.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn validator_proxy_binding(
+            &self,
+            validator_proxy: ComponentAddress,
+        ) -> ValidatorProxyBinding {
+            self.validator_proxies
+                .get(&validator_proxy)
+                .unwrap_or_else(|| {
+                    NodeWardenError::NotTheValidatorProxy(validator_proxy)
+                        .raise()
+                })
+                .clone()
+        }
+
+        /// Looks up the local ID of an access key badge of this component, mapping
+        /// honoured access key badges to their imported counterparts.
+        ///
+        /// # Panics
+        /// Panics if the badge is neither an access key badge nor an honoured one.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn access_key_badge_local_id(
+            &self,
+            access_key_badge: &NonFungibleGlobalId,
+        ) -> NonFungibleLocalId {
+            let local_id = access_key_badge.local_id().clone();
+            if access_key_badge.resource_address()
+                == self.access_key_badge_resource_manager.address()
+            {
+                return local_id;
+            }
+            if Some(access_key_badge.resource_address())
+                != self.honoured_access_key_resource
+            {
+                NodeWardenError::NotAnAccessKeyBadge.raise();
+            }
+            self.get_honoured_access_key_badge(local_id)
+                .unwrap_or_else(|| NodeWardenError::NotAnAccessKeyBadge.raise())
+        }
+
+        /// Returns the global ID of the single badge in a proof. Its resource is checked
+        /// by `access_key_badge_local_id`.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn proven_access_key_badge(
+            proof: NonFungibleProof,
+        ) -> NonFungibleGlobalId {
+            let check_skipped = proof.skip_checking();
+            NonFungibleGlobalId::new(
+                check_skipped.resource_address(),
+                check_skipped.non_fungible_local_id(),
+            )
+        }
+
+        /// Records a newly minted access key badge, so it can be exported.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn record_access_key_badge(&mut self, local_id: NonFungibleLocalId) {
+            self.access_key_badges
+                .insert(self.access_key_badge_count, local_id);
+            self.access_key_badge_count += 1;
+        }
+
+        /// Collects the state of an access key badge, to carry it over to another component.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn export_access_key_badge(
+            &self,
+            access_key_badge_local_id: &NonFungibleLocalId,
+        ) -> AccessKeyBadgeExport {
+            AccessKeyBadgeExport {
+                local_id: access_key_badge_local_id.clone(),
+                data: self
+                    .access_key_badge_resource_manager
+                    .get_non_fungible_data::<AccessKeyBadgeData>(
+                        access_key_badge_local_id,
+                    ),
+                treasury_allowance: self
+                    .treasury_allowance(access_key_badge_local_id),
+                issuance_quota: self
+                    .issuance_quotas
+                    .get(access_key_badge_local_id)
+                    .map(|quota| *quota)
+                    .unwrap_or_default(),
+            }
+        }
+
+        /// Mints access key badges from their exported state, carrying over their treasury
+        /// allowance and issuance quota. Issuers are linked to their import, if it is part
+        /// of the same batch.
+        ///
+        /// # Returns
+        ///
+        /// The old local ID of every access key badge, with its import.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn import_access_key_badges(
+            &mut self,
+            access_key_badges: Vec<AccessKeyBadgeExport>,
+        ) -> Vec<(NonFungibleLocalId, NonFungibleBucket)> {
+            let mut imported_local_ids = IndexMap::new();
+            let mut imports = Vec::new();
+            for AccessKeyBadgeExport {
+                local_id,
+                data,
+                treasury_allowance,
+                issuance_quota,
+            } in access_key_badges
+            {
+                let access_key_badge = self
+                    .access_key_badge_resource_manager
+                    .mint_ruid_non_fungible(AccessKeyBadgeData {
+                        issuer: data.issuer.as_ref().and_then(|issuer| {
+                            imported_local_ids.get(issuer).cloned()
+                        }),
+                        node_warden_component_address: Runtime::global_address(
+                        ),
+                        ..data
+                    });
+                let new_local_id = access_key_badge.non_fungible_local_id();
+                self.record_access_key_badge(new_local_id.clone());
+                self.treasury_allowances
+                    .insert(new_local_id.clone(), treasury_allowance);
+                self.issuance_quotas
+                    .insert(new_local_id.clone(), issuance_quota);
+
+                imported_local_ids.insert(local_id.clone(), new_local_id);
+                imports.push((local_id, access_key_badge));
+            }
+            imports
+        }
+
+        /// Returns the custody vault of the owner stake units of the given validator,
+        /// creating it first if it does not exist yet.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn owner_stake_unit_vault(
+            &mut self,
+            validator_address: ComponentAddress,
+        ) -> KeyValueEntryRefMut<'_, FungibleVault> {
+            if self.owner_stake_units.get(&validator_address).is_none() {
+                self.owner_stake_units.insert(
+                    validator_address,
+                    FungibleVault::new(Self::stake_unit_resource(
+                        validator_address,
+                    )),
+                );
+            }
+            self.owner_stake_units.get_mut(&validator_address).unwrap()
+        }
+
+        /// Returns the custody of the claim NFTs of the given validator,
+        /// creating it first if it does not exist yet.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn claim_nft_custody(
+            &mut self,
+            validator_address: ComponentAddress,
+        ) -> KeyValueEntryRefMut<'_, ClaimNftCustody> {
+            if self.claim_nfts.get(&validator_address).is_none() {
+                self.claim_nfts.insert(
+                    validator_address,
+                    ClaimNftCustody::new(Self::claim_nft_resource(
+                        validator_address,
+                    )),
+                );
+            }
+            self.claim_nfts.get_mut(&validator_address).unwrap()
+        }
+
+        /// Returns the custody of the claim NFTs of `compound_owner_rewards` for the
+        /// given validator, creating it first if it does not exist yet.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn compound_claim_nft_custody(
+            &mut self,
+            validator_address: ComponentAddress,
+        ) -> KeyValueEntryRefMut<'_, ClaimNftCustody> {
+            if self.compound_claim_nfts.get(&validator_address).is_none() {
+                self.compound_claim_nfts.insert(
+                    validator_address,
+                    ClaimNftCustody::new(Self::claim_nft_resource(
+                        validator_address,
+                    )),
+                );
+            }
+            self.compound_claim_nfts
+                .get_mut(&validator_address)
+                .unwrap()
+        }
+
+        /// Looks up the claim NFT resource of the given validator through its metadata.
+        ///
+        /// # Panics
+        /// Panics if the component is not a validator.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn claim_nft_resource(
+            validator_address: ComponentAddress,
+        ) -> ResourceAddress {
+            let validator: Global<Validator> = validator_address.into();
+            validator
+                .get_metadata::<_, GlobalAddress>("claim_nft")
+                .ok()
+                .flatten()
+                .and_then(|claim_nft| ResourceAddress::try_from(claim_nft).ok())
+                .unwrap_or_else(|| {
+                    NodeWardenError::NoClaimNftResource(validator_address)
+                        .raise()
+                })
+        }
+
+        /// Looks up the stake unit resource of the given validator through its metadata.
+        ///
+        /// # Panics
+        /// Panics if the component is not a validator.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn stake_unit_resource(
+            validator_address: ComponentAddress,
+        ) -> ResourceAddress {
+            let validator: Global<Validator> = validator_address.into();
+            validator
+                .get_metadata::<_, GlobalAddress>("pool_unit")
+                .ok()
+                .flatten()
+                .and_then(|pool_unit| ResourceAddress::try_from(pool_unit).ok())
+                .unwrap_or_else(|| {
+                    NodeWardenError::NoStakeUnitResource(validator_address)
+                        .raise()
+                })
+        }
+
+        /// Checks the proof against the proposed action and targeted validator.
+        ///
+        /// * `proof` - The incoming proof to check.
+        /// * `validator_address` - The validator the action is targeted at.
+        /// * `action` - The action to check, with everything its delegated method checks.
         ///
         /// # Returns
         ///
-        /// A bucket containing the Validator’s Stake Unit resource
+        /// The local ID of the access key badge that authorized the action, or `None`
+        /// if the proof is of the owner badge.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if the proof is not valid for the given action.
+        /// This happens if:
+        /// - The proof is not from the owner badge or the access key badge.
+        /// - One of the access key badges is compromised or misses its second factor.
+        /// - None of the access key badges passes all checks of the action, like its
+        ///   permission, validator scope, allowlists and treasury allowance.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn check_proof(
+            &self,
+            proof: NonFungibleProof,
+            validator_address: ComponentAddress,
+            action: &ProposedAction,
+        ) -> Option<NonFungibleLocalId> {
+            // Honoured access key badges of the component migrated from count as access key badges.
+            let is_honoured = Some(proof.resource_address())
+                == self.honoured_access_key_resource;
+
+            // It should always be either the owner badge or the access key badge in the proof.
+            if proof.resource_address()
+                != self.node_warden_owner_badge_resource_manager.address()
+                && proof.resource_address()
+                    != self.access_key_badge_resource_manager.address()
+                && !is_honoured
+            {
+                NodeWardenError::WrongBadgeResource.raise();
+            }
+
+            // If the proof is from the access key badge, we need to do the additional permission checks.
+            if proof.resource_address()
+                == self.access_key_badge_resource_manager.address()
+                || is_honoured
+            {
+                // Skip the check, we already validated the proof and we know its address here.
+                let check_skipped = proof.skip_checking();
+                let access_key_badges: Vec<(
+                    NonFungibleLocalId,
+                    AccessKeyBadgeData,
+                )> = check_skipped
+                    .non_fungible_local_ids()
+                    .into_iter()
+                    .map(|local_id| {
+                        // Honoured access key badges stand for their imported counterparts
+                        let local_id = if is_honoured {
+                            self.get_honoured_access_key_badge(local_id)
+                                .unwrap_or_else(|| {
+                                    NodeWardenError::NotAnAccessKeyBadge.raise()
+                                })
+                        } else {
+                            local_id
+                        };
+                        let access_key_badge_data = self
+                            .access_key_badge_resource_manager
+                            .get_non_fungible_data::<AccessKeyBadgeData>(
+                                &local_id,
+                            );
+                        (local_id, access_key_badge_data)
+                    })
+                    .collect();
+
+                // The most restrictive limits of all access key badges in the proof are honoured:
+                // none of them may be compromised, and the transaction must be signed by the
+                // second factor of each of them, if any.
+                for (_, access_key_badge_data) in access_key_badges.iter() {
+                    if access_key_badge_data.compromised {
+                        NodeWardenError::AccessKeyCompromised.raise();
+                    }
+                    assert_required_signer(access_key_badge_data);
+                }
+
+                // The permissions are the union of those of the access key badges in the proof.
+                // The first one that passes all checks of the action, including its own
+                // allowances and allowlists, authorizes it.
+                let mut denial = None;
+                for (access_key_badge_local_id, access_key_badge_data) in
+                    access_key_badges
+                {
+                    match self.evaluate_action(
+                        &access_key_badge_local_id,
+                        &access_key_badge_data,
+                        validator_address,
+                        action,
+                    ) {
+                        Ok(()) => {
+                            Runtime::emit_event(AccessKeyUsedEvent {
+                                access_key_badge_local_id:
+                                    access_key_badge_local_id.clone(),
+                                validator_address,
+                                permission_type: action.permission_type(),
+                            });
+                            return Some(access_key_badge_local_id);
+                        }
+                        Err(error) => {
+                            denial.get_or_insert(error);
+                        }
+                    }
+                }
+                denial
+                    .unwrap_or(NodeWardenError::PermissionDenied(
+                        action.permission_type(),
+                    ))
+                    .raise();
+            }
+
+            // Else, the proof must be from the owner badge. In that case, let it go through.
+            // Owner badge doesn't need explicit permissions.
+            None
+        }
+
+        /// Evaluates the rules every delegated method checks for an access key badge:
+        /// it must not be compromised, must have the permission, and must be scoped
+        /// to the validator. The second factor is checked separately, as it depends
+        /// on the signatures of the transaction.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_access_key(
+            access_key_badge_data: &AccessKeyBadgeData,
+            validator_address: ComponentAddress,
+            permission_type: PermissionType,
+        ) -> Result<(), NodeWardenError> {
+            // Access key badges reported compromised are disabled for good.
+            if access_key_badge_data.compromised {
+                return Err(NodeWardenError::AccessKeyCompromised);
+            }
+
+            // Only allow if the access key badge has the required permission.
+            if !access_key_badge_data
+                .permissions
+                .is_allowed(&permission_type)
+            {
+                return Err(NodeWardenError::PermissionDenied(permission_type));
+            }
+
+            // Only allow if the access key badge applies to the targeted validator.
+            if permission_type.acts_on_validator()
+                && !access_key_badge_data
+                    .validator_scope
+                    .includes(&validator_address)
+            {
+                return Err(NodeWardenError::NotScopedToValidator(
+                    validator_address,
+                ));
+            }
+
+            Ok(())
+        }
+
+        /// Evaluates all rules the delegated method of the proposed action checks
+        /// for an access key badge, except for the second factor.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_action(
+            &self,
+            access_key_badge_local_id: &NonFungibleLocalId,
+            access_key_badge_data: &AccessKeyBadgeData,
+            validator_address: ComponentAddress,
+            action: &ProposedAction,
+        ) -> Result<(), NodeWardenError> {
+            let permission_type = action.permission_type();
+            if permission_type.acts_on_validator()
+                && !self.validators.contains_key(&validator_address)
+            {
+                return Err(NodeWardenError::NoValidatorOwnerBadge(
+                    validator_address,
+                ));
+            }
+
+            Self::evaluate_access_key(
+                access_key_badge_data,
+                validator_address,
+                permission_type,
+            )?;
+
+            match action {
+                ProposedAction::Permission(permission_type) => {
+                    // Otherwise the checks of the call would be skipped
+                    if permission_type.requires_proposed_action() {
+                        return Err(NodeWardenError::ProposedActionRequired(
+                            *permission_type,
+                        ));
+                    }
+                    Ok(())
+                }
+                ProposedAction::SetMetadata(entries) => {
+                    entries.iter().try_for_each(|(name, metadata)| {
+                        self.evaluate_metadata_schema(name, metadata)
+                    })
+                }
+                ProposedAction::GetValidatorRole(role)
+                | ProposedAction::SetValidatorRole(role) => {
+                    self.evaluate_delegable_role(role)
+                }
+                ProposedAction::StakeFromTreasury(amount) => self
+                    .evaluate_treasury_allowance(
+                        access_key_badge_local_id,
+                        *amount,
+                    ),
+                ProposedAction::CallValidatorMethod(method_name) => {
+                    Self::evaluate_validator_method(
+                        access_key_badge_data,
+                        method_name,
+                    )
+                }
+                ProposedAction::CallComponentMethod(component_method) => {
+                    Self::evaluate_component_method(
+                        access_key_badge_data,
+                        component_method,
+                    )
+                }
+            }
+        }
+
+        /// Evaluates a delegated metadata write against the schema of its field, if any.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_metadata_schema(
+            &self,
+            name: &str,
+            metadata: &MetadataValue,
+        ) -> Result<(), NodeWardenError> {
+            match self.metadata_schemas.get(name) {
+                Some(schema) => schema.validate(name, metadata),
+                None => Ok(()),
+            }
+        }
+
+        /// Evaluates whether access key badges may manage the given role of the validator.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_delegable_role(
+            &self,
+            role: &ValidatorRole,
+        ) -> Result<(), NodeWardenError> {
+            if !self.delegable_roles.contains(role) {
+                return Err(NodeWardenError::RoleNotDelegable);
+            }
+            Ok(())
+        }
+
+        /// Returns the amount of XRD an access key badge may still stake from the treasury.
+        ///
+        // This is not marked pub, as it is an internal helper
+        fn treasury_allowance(
+            &self,
+            access_key_badge_local_id: &NonFungibleLocalId,
+        ) -> Decimal {
+            self.treasury_allowances
+                .get(access_key_badge_local_id)
+                .map(|allowance| *allowance)
+                .unwrap_or_default()
+        }
+
+        /// Evaluates whether an access key badge may stake the given amount from the treasury.
         ///
-        /// This function is a thin wrapper of the `stake_as_owner` method on the `Validator` component.
-        pub fn stake_as_owner(
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_treasury_allowance(
             &self,
-            proof: NonFungibleProof,
-            stake: FungibleBucket,
-        ) -> FungibleBucket {
-            self.check_proof(proof, PermissionType::StakeAsOwner);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.stake_as_owner(stake)
-            })
+            access_key_badge_local_id: &NonFungibleLocalId,
+            amount: Decimal,
+        ) -> Result<(), NodeWardenError> {
+            if amount > self.treasury_allowance(access_key_badge_local_id) {
+                return Err(NodeWardenError::TreasuryAllowanceExceeded);
+            }
+            Ok(())
         }
 
-        /// Registers the Validator to be available to validate and propose transactions in Consensus
+        /// Evaluates whether a validator method is on the allowlist of an access key badge.
         ///
-        /// * `proof` - The proof of authorization.
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_validator_method(
+            access_key_badge_data: &AccessKeyBadgeData,
+            method_name: &str,
+        ) -> Result<(), NodeWardenError> {
+            if !access_key_badge_data
+                .allowed_validator_methods
+                .contains(method_name)
+            {
+                return Err(NodeWardenError::ValidatorMethodNotAllowed(
+                    method_name.to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        /// Evaluates whether a component method is on the allowlist of an access key badge.
         ///
-        /// This function is a thin wrapper of the `register` method on the `Validator` component.
-        pub fn register(&self, proof: NonFungibleProof) {
-            self.check_proof(proof, PermissionType::Register);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.register()
-            })
+        // This is not marked pub, as it is an internal helper
+        fn evaluate_component_method(
+            access_key_badge_data: &AccessKeyBadgeData,
+            component_method: &ComponentMethod,
+        ) -> Result<(), NodeWardenError> {
+            if !access_key_badge_data
+                .allowed_component_methods
+                .contains(component_method)
+            {
+                return Err(NodeWardenError::ComponentMethodNotAllowed(
+                    component_method.method_name.clone(),
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[blueprint]
+mod validator_proxy {
+    enable_function_auth! {
+        instantiate => rule!(allow_all);
+    }
+    enable_method_auth! {
+        methods {
+            // These methods mirror the owner methods of the validator component.
+            // These are public, because their access is checked against the
+            // auth zone of the caller in the methods themselves.
+            register => PUBLIC;
+            unregister => PUBLIC;
+            stake_as_owner => PUBLIC;
+            update_key => PUBLIC;
+            update_fee => PUBLIC;
+            update_accept_delegated_stake => PUBLIC;
+            signal_protocol_update_readiness => PUBLIC;
+            lock_owner_stake_units => PUBLIC;
+            start_unlock_owner_stake_units => PUBLIC;
+            finish_unlock_owner_stake_units => PUBLIC;
+
+            // These methods mirror the public methods of the validator component
+            stake => PUBLIC;
+            unstake => PUBLIC;
+            claim_xrd => PUBLIC;
+            accepts_delegated_stake => PUBLIC;
+            total_stake_xrd_amount => PUBLIC;
+            total_stake_unit_supply => PUBLIC;
+            get_redemption_value => PUBLIC;
+            get_protocol_update_readiness => PUBLIC;
+        }
+    }
+    struct ValidatorProxy {
+        // The NodeWarden component holding the owner badge of the validator
+        node_warden: Global<NodeWarden>,
+        // The validator this proxy acts on
+        validator_address: ComponentAddress,
+    }
+    impl ValidatorProxy {
+        /// Creates a new proxy for a validator managed by the given NodeWarden component.
+        /// The proxy only works once NodeWarden has registered it for the validator,
+        /// which `create_validator_proxy` on NodeWarden does.
+        pub fn instantiate(
+            node_warden_address: ComponentAddress,
+            validator_address: ComponentAddress,
+        ) -> Global<ValidatorProxy> {
+            Self {
+                node_warden: node_warden_address.into(),
+                validator_address,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .metadata(metadata! (
+                init {
+                    "name" => "NodeWarden Validator Proxy", locked;
+                    "description" => "A drop-in proxy of a Radix validator component, authorized by NodeWarden badges in the auth zone", locked;
+                    "node_warden_component_address" => node_warden_address, locked;
+                    "validator_component_address" => validator_address, locked;
+                }
+            ))
+            .globalize()
+        }
+
+        /// Registers the Validator to be available to validate and propose transactions in Consensus
+        pub fn register(&self) {
+            self.with_validator_owner_badge(
+                PermissionType::Register,
+                |validator| validator.register(),
+            )
         }
 
         /// Unregisters the validator.
+        pub fn unregister(&self) {
+            self.with_validator_owner_badge(
+                PermissionType::Unregister,
+                |validator| validator.unregister(),
+            )
+        }
+
+        /// Stakes XRD to the Validator as owner.
         ///
-        /// * `proof` - The proof of authorization.
+        /// * `stake` - The XRD to stake.
         ///
-        /// This function is a thin wrapper of the `unregister` method on the `Validator` component.
-        pub fn unregister(&self, proof: NonFungibleProof) {
-            self.check_proof(proof, PermissionType::Unregister);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.unregister()
-            })
+        /// # Returns
+        ///
+        /// A bucket containing the Validator’s Stake Unit resource
+        pub fn stake_as_owner(&self, stake: FungibleBucket) -> FungibleBucket {
+            self.with_validator_owner_badge(
+                PermissionType::StakeAsOwner,
+                |validator| validator.stake_as_owner(stake),
+            )
         }
 
         /// Updates the public key of the Validator.
         ///
-        /// * `proof` - The proof of authorization.
         /// * `key` - The public key to replace the Validator’s Consensus public key with.
-        ///
-        /// This function is a thin wrapper of the `update_key` method on the `Validator` component.
-        pub fn update_key(
-            &self,
-            proof: NonFungibleProof,
-            key: Secp256k1PublicKey,
-        ) {
-            self.check_proof(proof, PermissionType::UpdateKey);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.update_key(key)
-            })
+        pub fn update_key(&self, key: Secp256k1PublicKey) {
+            self.with_validator_owner_badge(
+                PermissionType::UpdateKey,
+                |validator| validator.update_key(key),
+            )
         }
 
         /// Changes the fee for the validator.
         ///
-        /// * `proof` - The proof of authorization.
         /// * `new_fee_factor` - A decimal >= 0.0 and <= 1.0 representing the new fee fraction.
+        pub fn update_fee(&self, new_fee_factor: Decimal) {
+            self.with_validator_owner_badge(
+                PermissionType::UpdateFee,
+                |validator| validator.update_fee(new_fee_factor),
+            )
+        }
+
+        /// Updates the flag deciding whether the Validator should accept delegated stake.
         ///
-        /// This function is a thin wrapper of the `update_fee` method on the `Validator` component.
-        pub fn update_fee(
+        /// * `accept_delegated_stake` - Whether to accept delegated stake.
+        pub fn update_accept_delegated_stake(
             &self,
-            proof: NonFungibleProof,
-            new_fee_factor: Decimal,
+            accept_delegated_stake: bool,
         ) {
-            self.check_proof(proof, PermissionType::UpdateFee);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.update_fee(new_fee_factor)
-            })
+            self.with_validator_owner_badge(
+                PermissionType::UpdateAcceptDelegatedStake,
+                |validator| {
+                    validator
+                        .update_accept_delegated_stake(accept_delegated_stake)
+                },
+            )
         }
 
-        /// Locks the given Stake Units in an internal “delayed withdrawal”
-        /// vault (as a way of showing the Owner’s commitment to running the Validator).
+        /// Signals on ledger what protocol version to potentially change to.
         ///
-        /// * `proof` - The proof of authorization.
-        /// * `stake_unit_bucket` - A bucket of Stake Units
+        /// * `vote` - The protocol version to signal readiness for.
+        pub fn signal_protocol_update_readiness(&self, vote: String) {
+            self.with_validator_owner_badge(
+                PermissionType::SignalProtocolUpdateReadiness,
+                |validator| validator.signal_protocol_update_readiness(vote),
+            )
+        }
+
+        /// Locks the given Stake Units in the Validator's internal “delayed withdrawal” vault.
         ///
-        /// This function is a thin wrapper of the `lock_owner_stake_units` method on the `Validator` component.
+        /// * `stake_unit_bucket` - The Stake Units to lock.
         pub fn lock_owner_stake_units(
             &self,
-            proof: NonFungibleProof,
             stake_unit_bucket: FungibleBucket,
         ) {
-            self.check_proof(proof, PermissionType::LockOwnerStakeUnits);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.lock_owner_stake_units(stake_unit_bucket)
-            })
+            self.with_validator_owner_badge(
+                PermissionType::LockOwnerStakeUnits,
+                |validator| validator.lock_owner_stake_units(stake_unit_bucket),
+            )
         }
 
         /// Begins the process of unlocking the Owner’s Stake Units.
         ///
-        /// The requested amount of Stake Units (if available) will be ready for
-        /// withdrawal after the Network-configured number of Epochs is reached.
-        ///
-        /// * `proof` - The proof of authorization.
         /// * `requested_stake_unit_amount` - The amount of Stake Units to start unlocking.
-        ///
-        /// This function is a thin wrapper of the `start_unlock_owner_stake_units` method on the `Validator` component.
         pub fn start_unlock_owner_stake_units(
             &self,
-            proof: NonFungibleProof,
             requested_stake_unit_amount: Decimal,
         ) {
-            self.check_proof(proof, PermissionType::StartUnlockOwnerStakeUnits);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator
-                    .start_unlock_owner_stake_units(requested_stake_unit_amount)
-            })
+            self.with_validator_owner_badge(
+                PermissionType::StartUnlockOwnerStakeUnits,
+                |validator| {
+                    validator.start_unlock_owner_stake_units(
+                        requested_stake_unit_amount,
+                    )
+                },
+            )
         }
 
-        /// Finishes the process of unlocking the Owner’s Stake Units by withdrawing all the
-        /// pending amounts which have reached their target Epoch and
-        /// thus are already available - potentially none.
+        /// Finishes the process of unlocking the Owner’s Stake Units.
         ///
-        /// * `proof` - The proof of authorization.
+        /// The unlocked Stake Units are deposited into the custody vault of NodeWarden,
+        /// so the caller never possesses them.
         ///
         /// # Returns
-        /// A bucket of Stake Units
         ///
-        /// This function is a thin wrapper of the `finish_unlock_owner_stake_units` method on the `Validator` component.
-        pub fn finish_unlock_owner_stake_units(
-            &self,
-            proof: NonFungibleProof,
-        ) -> FungibleBucket {
-            self.check_proof(
-                proof,
+        /// An empty bucket of the Validator’s Stake Unit resource, in place of the
+        /// unlocked Stake Units.
+        pub fn finish_unlock_owner_stake_units(&self) -> FungibleBucket {
+            let stake_unit_bucket = self.with_validator_owner_badge(
                 PermissionType::FinishUnlockOwnerStakeUnits,
+                |validator| validator.finish_unlock_owner_stake_units(),
+            );
+            let stake_unit_resource = stake_unit_bucket.resource_address();
+            self.node_warden.deposit_validator_proxy_stake_units(
+                Runtime::global_address(),
+                stake_unit_bucket,
             );
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.finish_unlock_owner_stake_units()
-            })
+            FungibleBucket::new(stake_unit_resource)
         }
 
-        /// Updates the flag deciding whether the Validator should accept delegated stake.
-        ///
-        /// * `proof` - The proof of authorization.
-        /// * `accept_delegated_stake` - Whether to accept delegated stake.
-        ///
-        /// This function is a thin wrapper of the `update_accept_delegated_stake` method on the `Validator` component.
-        pub fn update_accept_delegated_stake(
-            &self,
-            proof: NonFungibleProof,
-            accept_delegated_stake: bool,
-        ) {
-            self.check_proof(proof, PermissionType::UpdateAcceptDelegatedStake);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.update_accept_delegated_stake(accept_delegated_stake)
-            })
+        /// Stakes XRD to the Validator.
+        pub fn stake(&self, stake: FungibleBucket) -> FungibleBucket {
+            self.validator().stake(stake)
         }
 
-        /// Signals on ledger what protocol version to potentially change to. Used by Consensus to coordinate protocol updates.
-        ///
-        /// * `proof` - The proof of authorization.
-        /// * `protocol_version_name` - The protocol version to signal readiness for.
-        ///
-        /// This function is a thin wrapper of the `signal_protocol_update_readiness` method on the `Validator` component.
-        pub fn signal_protocol_update_readiness(
+        /// Unstakes Stake Units from the Validator.
+        pub fn unstake(
             &self,
-            proof: NonFungibleProof,
-            protocol_version_name: String,
-        ) {
-            self.check_proof(
-                proof,
-                PermissionType::SignalProtocolUpdateReadiness,
-            );
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator
-                    .signal_protocol_update_readiness(protocol_version_name)
-            })
+            stake_unit_bucket: FungibleBucket,
+        ) -> NonFungibleBucket {
+            self.validator().unstake(stake_unit_bucket)
         }
 
-        // #########################################################
-        // ##### Additional methods ################################
-        // #########################################################
+        /// Claims the XRD of ready claim NFTs of the Validator.
+        pub fn claim_xrd(&self, bucket: NonFungibleBucket) -> FungibleBucket {
+            self.validator().claim_xrd(bucket)
+        }
 
-        /// Set metadata for the validator component
-        ///
-        /// * `proof` - The proof of authorization.
-        /// * `name` - The name of the metadata field.
-        /// * `metadata` - The metadata value to set.
-        ///
-        pub fn set_metadata(
-            &self,
-            proof: NonFungibleProof,
-            name: String,
-            metadata: MetadataValue,
-        ) {
-            self.check_proof(proof, PermissionType::SetMetadata);
-            self.do_with_validator_owner_badge_proof(
-                // We can't seem to get around this good old match...
-                |validator| match metadata {
-                    MetadataValue::String(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::Bool(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::U8(val) => validator.set_metadata(name, val),
-                    MetadataValue::U32(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::U64(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::I32(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::I64(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::Decimal(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::GlobalAddress(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::PublicKey(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::NonFungibleGlobalId(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::NonFungibleLocalId(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::Instant(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::Url(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::Origin(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::PublicKeyHash(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::StringArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::BoolArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::U8Array(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::U32Array(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::U64Array(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::I32Array(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::I64Array(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::DecimalArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::GlobalAddressArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::PublicKeyArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::NonFungibleGlobalIdArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::NonFungibleLocalIdArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::InstantArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::UrlArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::OriginArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                    MetadataValue::PublicKeyHashArray(val) => {
-                        validator.set_metadata(name, val)
-                    }
-                },
-            );
+        /// Returns whether the Validator accepts delegated stake.
+        pub fn accepts_delegated_stake(&self) -> bool {
+            self.validator().accepts_delegated_stake()
         }
 
-        /// Remove metadata from the validator component
-        ///
-        /// * `proof` - The proof of authorization.
-        /// * `name` - The name of the metadata field.
-        ///
-        pub fn remove_metadata(&self, proof: NonFungibleProof, name: String) {
-            self.check_proof(proof, PermissionType::RemoveMetadata);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.remove_metadata(name);
-            });
+        /// Returns the amount of XRD staked to the Validator.
+        pub fn total_stake_xrd_amount(&self) -> Decimal {
+            self.validator().total_stake_xrd_amount()
         }
 
-        /// Lock a piece of metadata on the validator component
-        ///
-        /// * `proof` - The proof of authorization.
-        /// * `name` - The name of the metadata field.
-        ///
-        pub fn lock_metadata(&self, proof: NonFungibleProof, name: String) {
-            self.check_proof(proof, PermissionType::LockMetadata);
-            self.do_with_validator_owner_badge_proof(|validator| {
-                validator.lock_metadata(name);
-            });
+        /// Returns the total supply of Stake Units of the Validator.
+        pub fn total_stake_unit_supply(&self) -> Decimal {
+            self.validator().total_stake_unit_supply()
         }
 
-        /// Create a proof of the validator owner badge.
-        /// The proof can be used to call any of the validator component's methods.
-        ///
-        /// # Warning
-        /// By enabling the permission for this method, you allow the caller to create a proof of the validator owner badge.
-        /// This essentially gives the caller the ability to perform any action that requires the validator owner badge, including
-        /// things like changing fees and withdrawing stake units from the validator's vault.
-        pub fn create_validator_owner_badge_proof(
+        /// Returns the XRD value of the given amount of Stake Units.
+        pub fn get_redemption_value(
             &self,
-            proof: NonFungibleProof,
-        ) -> NonFungibleProof {
-            self.check_proof(
-                proof,
-                PermissionType::CreateValidatorOwnerBadgeProof,
-            );
-            self.validator_owner_badge.create_proof_of_non_fungibles(
-                &self.validator_owner_badge.non_fungible_local_ids(1),
-            )
+            amount_of_stake_units: Decimal,
+        ) -> Decimal {
+            self.validator().get_redemption_value(amount_of_stake_units)
+        }
+
+        /// Returns the protocol version the Validator signals readiness for.
+        pub fn get_protocol_update_readiness(&self) -> Option<String> {
+            self.validator().get_protocol_update_readiness()
         }
 
         // #########################################################
         // ##### Some non-public helpers ###########################
         // #########################################################
 
-        /// Executes a closure with permission of the validator owner badge.
-        ///
-        /// * `f` - The closure to execute with authorization of the validator owner badge.
-        ///     This closure takes a mutable reference to the `Validator` component, allows
-        ///     you to perform actions on the validator.
+        /// Returns the validator this proxy acts on.
         ///
         // This is not marked pub, as it is an internal helper
-        fn do_with_validator_owner_badge_proof<F, O>(&self, f: F) -> O
-        where
-            F: FnOnce(&mut Global<Validator>) -> O,
-        {
-            let mut validator: Global<Validator> = self
-                .validator_address
-                .expect("The component address of the validator component should be known at this point")
-                .into();
-            let non_fungible_id =
-                self.validator_owner_badge.non_fungible_local_id();
-            self.validator_owner_badge.authorize_with_non_fungibles(
-                &indexset!(non_fungible_id),
-                || f(&mut validator),
-            )
+        fn validator(&self) -> Global<Validator> {
+            self.validator_address.into()
         }
 
-        /// Checks the proof against the required permission type.
-        ///
-        /// * `proof` - The incoming proof to check.
-        /// * `permission_type` - The permission type to check for.
+        /// Checks the auth zone of the caller against the access rule of this proxy,
+        /// then executes a closure with permission of the owner badge of the validator,
+        /// which NodeWarden only lends if the badge of this proxy has the permission.
         ///
-        /// # Panics
-        ///
-        /// Will panic if the proof is not valid for the given permission type.
-        /// This happens if:
-        /// - The proof is not from the owner badge or the access key badge.
-        /// - The access key badge does not have the required permission.
+        /// * `permission_type` - The permission the action requires.
+        /// * `f` - The closure to execute with authorization of the validator owner badge.
         ///
         // This is not marked pub, as it is an internal helper
-        fn check_proof(
+        fn with_validator_owner_badge<F, O>(
             &self,
-            proof: NonFungibleProof,
             permission_type: PermissionType,
-        ) {
-            // It should always be either the owner badge or the access key badge in the proof.
-            assert!(
-                proof.resource_address()
-                    == self.node_warden_owner_badge_resource_manager.address()
-                    || proof.resource_address()
-                        == self.access_key_badge_resource_manager.address()
+            f: F,
+        ) -> O
+        where
+            F: FnOnce(&mut Global<Validator>) -> O,
+        {
+            let validator_proxy = Runtime::global_address();
+            Runtime::assert_access_rule(
+                self.node_warden.get_access_rule(validator_proxy),
             );
-
-            // If the proof is from the access key badge, we need to do the additional permission checks.
-            if proof.resource_address()
-                == self.access_key_badge_resource_manager.address()
-            {
-                // Skip the check, we already validated the proof and we know its address here.
-                let check_skipped = proof.skip_checking();
-                let access_key_badge_data =
-                    check_skipped.non_fungible::<AccessKeyBadgeData>();
-
-                // Only allow if the access key badge has the required permission.
-                assert!(
-                    access_key_badge_data
-                        .data()
-                        .permissions
-                        .is_allowed(&permission_type),
-                    "Access key badge does not have permission for: {}",
-                    permission_type.to_string()
-                );
-            }
-
-            // Else, the proof must be from the owner badge. In that case, let it go through.
-            // Owner badge doesn't need explicit permissions.
+            let proof = self
+                .node_warden
+                .create_validator_proxy_proof(validator_proxy, permission_type);
+            let mut validator = self.validator();
+            proof.authorize(|| f(&mut validator))
         }
     }
 }
@@ -1,4 +1,10 @@
-use node_warden::{AccessKeyBadgeData, AccessKeyPermissions};
+use node_warden::{
+    AccessKeyBadgeData, AccessKeyLabel, AccessKeyPermissions, ComponentMethod,
+    CompoundPolicy, MetadataSchema, MetadataValueType, MigrationMode,
+    NodeWardenConfiguration, NodeWardenError, NodeWardenMetadataTarget,
+    NodeWardenSnapshot, OwnerRewardsCompoundedEvent, PermissionType,
+    ProposedAction, ValidatorRole, ValidatorScope,
+};
 use scrypto_test::prelude::*;
 
 #[derive(Clone, Debug)]
@@ -181,6 +187,7 @@ impl CustomTestEnvironment {
         by_account: &Account,
         to_account: &Account,
         permissions: AccessKeyPermissions,
+        validator_scope: ValidatorScope,
         access_key_resource: ResourceAddress,
     ) -> Result<NonFungibleGlobalId, RuntimeError> {
         let manifest = ManifestBuilder::new()
@@ -192,7 +199,11 @@ impl CustomTestEnvironment {
             .call_method(
                 component_address,
                 "create_access_key_badge",
-                manifest_args!(permissions),
+                manifest_args!(
+                    permissions,
+                    validator_scope,
+                    access_key_label(to_account)
+                ),
             )
             .deposit_entire_worktop(to_account.account_address)
             .build();
@@ -280,6 +291,7 @@ impl CustomTestEnvironment {
         component_address: ComponentAddress,
         account: &Account,
         node_warden_owner_badge: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
     ) {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -290,7 +302,7 @@ impl CustomTestEnvironment {
             .call_method(
                 component_address,
                 "withdraw_validator_owner_badge",
-                manifest_args!(),
+                manifest_args!(validator_address),
             )
             .deposit_entire_worktop(account.account_address)
             .build();
@@ -313,6 +325,7 @@ impl CustomTestEnvironment {
         component_address: ComponentAddress,
         admin_account: &Account,
         access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
         fee: Decimal,
     ) {
         let manifest = ManifestBuilder::new()
@@ -325,7 +338,13 @@ impl CustomTestEnvironment {
             .call_method_with_name_lookup(
                 component_address,
                 "update_fee",
-                |lookup| manifest_args!(lookup.proof("proof"), fee),
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        fee
+                    )
+                },
             )
             .build();
 
@@ -382,6 +401,7 @@ impl CustomTestEnvironment {
         component_address: ComponentAddress,
         admin_account: &Account,
         access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
     ) {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -393,7 +413,9 @@ impl CustomTestEnvironment {
             .call_method_with_name_lookup(
                 component_address,
                 "register",
-                |lookup| manifest_args!(lookup.proof("proof")),
+                |lookup| {
+                    manifest_args!(lookup.proof("proof"), validator_address)
+                },
             )
             .build();
 
@@ -412,6 +434,7 @@ impl CustomTestEnvironment {
         component_address: ComponentAddress,
         admin_account: &Account,
         access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
     ) {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -423,7 +446,9 @@ impl CustomTestEnvironment {
             .call_method_with_name_lookup(
                 component_address,
                 "unregister",
-                |lookup| manifest_args!(lookup.proof("proof")),
+                |lookup| {
+                    manifest_args!(lookup.proof("proof"), validator_address)
+                },
             )
             .build();
 
@@ -443,6 +468,7 @@ impl CustomTestEnvironment {
         component_address: ComponentAddress,
         admin_account: &Account,
         access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
         name: &str,
         metadata: MetadataValue,
     ) {
@@ -459,6 +485,7 @@ impl CustomTestEnvironment {
                 |lookup| {
                     manifest_args!(
                         lookup.proof("proof"),
+                        validator_address,
                         name.to_string(),
                         metadata
                     )
@@ -477,42 +504,76 @@ impl CustomTestEnvironment {
         receipt.expect_commit_success();
     }
 
-    fn recall_and_burn_access_key_badge(
+    fn stake_as_owner(
+        &mut self,
+        component_address: ComponentAddress,
+        account: &Account,
+        badge_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        amount: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, amount, "stake")
+            .create_proof_from_account_of_non_fungible(
+                account.account_address,
+                badge_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "stake_as_owner",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        lookup.bucket("stake")
+                    )
+                },
+            )
+            .deposit_entire_worktop(account.account_address)
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&account.public_key)],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn deposit_owner_stake_units(
         &mut self,
         component_address: ComponentAddress,
         owner_account: &Account,
-        admin_account: &Account,
         owner_badge_global_id: NonFungibleGlobalId,
-        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        stake_unit_resource: ResourceAddress,
+        amount: Decimal,
     ) {
-        // find the vault address of access_key_global_id
-        let admin_vault: InternalAddress = InternalAddress::try_from_hex(
-            &self.runner.get_component_vaults(
-                admin_account.account_address,
-                access_key_global_id.resource_address(),
-            )[0]
-            .to_hex(),
-        )
-        .unwrap();
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
             .create_proof_from_account_of_non_fungible(
                 owner_account.account_address,
                 owner_badge_global_id.clone(),
             )
-            .call_method(
-                component_address,
-                "recall_access_key_badge",
-                manifest_args!(admin_vault, access_key_global_id.local_id()),
-            )
-            .take_all_from_worktop(
-                access_key_global_id.resource_address(),
-                "access_key",
+            .withdraw_from_account(
+                owner_account.account_address,
+                stake_unit_resource,
+                amount,
             )
+            .take_all_from_worktop(stake_unit_resource, "stake_units")
             .call_method_with_name_lookup(
                 component_address,
-                "burn_access_key_badge",
-                |lookup| manifest_args!(lookup.bucket("access_key")),
+                "deposit_owner_stake_units",
+                |lookup| {
+                    manifest_args!(
+                        validator_address,
+                        lookup.bucket("stake_units")
+                    )
+                },
             )
             .build();
 
@@ -526,197 +587,4394 @@ impl CustomTestEnvironment {
         println!("Transaction Receipt: {:?}", receipt);
         receipt.expect_commit_success();
     }
-}
-
-struct SimpleSetupStuff {
-    env: CustomTestEnvironment,
-    validator_component: ComponentAddress,
-    validator_owner: Account,
-    admin1: Account,
-    node_warden_results: NodeWardenInstantiateResult,
-    admin1_access_key: NonFungibleGlobalId,
-}
-
-fn simple_setup(permissions: AccessKeyPermissions) -> SimpleSetupStuff {
-    let mut env = CustomTestEnvironment::new();
-    let validator_owner = env.accounts[0].clone();
-    let (validator_component, owner_badge_nft_id) =
-        env.create_validator_component(&validator_owner).unwrap();
 
-    let node_warden_results = env.instantiate_node_warden(&validator_owner);
+    fn lock_owner_stake_units(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        amount: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "lock_owner_stake_units",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        amount
+                    )
+                },
+            )
+            .build();
 
-    let admin1 = env.accounts[1].clone();
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
 
-    let access_key_global_id = env
-        .create_access_key_badge(
-            node_warden_results.component_address.clone(),
-            node_warden_results.owner_badge.clone(),
-            &validator_owner,
-            &admin1,
-            permissions,
-            node_warden_results.access_key_resource.clone(),
-        )
-        .unwrap();
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
 
-    env.deposit_validator_owner_badge(
-        node_warden_results.component_address.clone(),
-        &validator_owner,
-        owner_badge_nft_id,
-        node_warden_results.owner_badge.clone(),
-    );
-    return SimpleSetupStuff {
-        env,
-        validator_owner,
-        validator_component,
-        admin1,
-        node_warden_results,
-        admin1_access_key: access_key_global_id,
-    };
-}
+    fn fund_treasury(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        amount: Decimal,
+        access_key_global_id: NonFungibleGlobalId,
+        allowance: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, amount, "xrd")
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method_with_name_lookup(
+                component_address,
+                "deposit_treasury_xrd",
+                |lookup| manifest_args!(lookup.bucket("xrd")),
+            )
+            .call_method(
+                component_address,
+                "update_access_key_badge_treasury_allowance",
+                manifest_args!(access_key_global_id.local_id(), allowance),
+            )
+            .deposit_entire_worktop(owner_account.account_address)
+            .build();
 
-#[test]
-fn create_env() {
-    let SimpleSetupStuff {
-        mut env,
-        validator_owner: _,
-        admin1,
-        node_warden_results,
-        admin1_access_key,
-        validator_component,
-    } = simple_setup(AccessKeyPermissions {
-        update_fee: true,
-        ..Default::default()
-    });
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
 
-    env.update_fee(
-        node_warden_results.component_address,
-        &admin1,
-        admin1_access_key.clone(),
-        dec!(0.069),
-    );
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
 
-    let validator_info = env.runner.get_validator_info(validator_component);
-    assert!(
-        validator_info
-            .validator_fee_change_request
-            .unwrap()
-            .new_fee_factor
-            == dec!(0.069)
-    );
-}
+    fn stake_from_treasury(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        amount: Decimal,
+        lock: bool,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "stake_from_treasury",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        amount,
+                        lock
+                    )
+                },
+            )
+            .build();
 
-#[test]
-fn successfully_update_fee() {
-    let SimpleSetupStuff {
-        mut env,
-        validator_owner: _,
-        admin1,
-        node_warden_results,
-        admin1_access_key,
-        validator_component,
-    } = simple_setup(AccessKeyPermissions {
-        update_fee: true,
-        ..Default::default()
-    });
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
 
-    env.update_fee(
-        node_warden_results.component_address,
-        &admin1,
-        admin1_access_key.clone(),
-        dec!(0.069),
-    );
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
 
-    let validator_info = env.runner.get_validator_info(validator_component);
-    assert!(
-        validator_info
-            .validator_fee_change_request
-            .unwrap()
+    fn start_unlock_owner_stake_units(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        amount: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "start_unlock_owner_stake_units",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        amount
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_compound_policy(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        compound_policy: CompoundPolicy,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_compound_policy",
+                manifest_args!(compound_policy),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn compound_owner_rewards(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+    ) -> OwnerRewardsCompoundedEvent {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "compound_owner_rewards",
+                |lookup| {
+                    manifest_args!(lookup.proof("proof"), validator_address)
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        self.runner
+            .extract_events_of_type::<OwnerRewardsCompoundedEvent>(
+                receipt.expect_commit_success(),
+            )
+            .pop()
+            .unwrap()
+    }
+
+    fn unstake(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        amount: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "unstake",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        amount
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn claim_xrd(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "claim_xrd",
+                |lookup| {
+                    manifest_args!(lookup.proof("proof"), validator_address)
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_access_key_badge_validator_methods(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        admin_badge_global_id: NonFungibleGlobalId,
+        method_name: &str,
+        allow: bool,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                admin_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_access_key_badge_validator_methods",
+                manifest_args!(
+                    access_key_global_id.local_id(),
+                    method_name.to_string(),
+                    allow
+                ),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn call_validator_update_fee(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        method_name: &str,
+        fee: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "call_validator_method",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        method_name.to_string(),
+                        (fee,)
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn allow_component_method(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        admin_badge_global_id: NonFungibleGlobalId,
+        component_method: ComponentMethod,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                admin_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_access_key_badge_component_methods",
+                manifest_args!(
+                    access_key_global_id.local_id(),
+                    component_method,
+                    true
+                ),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn call_component_method(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        component_method: ComponentMethod,
+        fee: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "call_component_method",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        component_method,
+                        (fee,)
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_delegable_role(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        role: ValidatorRole,
+        allow: bool,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_delegable_role",
+                manifest_args!(role, allow),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn set_validator_role(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        role: ValidatorRole,
+        rule: AccessRule,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "set_validator_role",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        role,
+                        rule
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn set_validator_metadata_directly(
+        &mut self,
+        account: &Account,
+        validator_address: ComponentAddress,
+        name: &str,
+        value: &str,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .set_metadata(validator_address, name, value.to_string())
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&account.public_key)],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_metadata_schema(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        name: &str,
+        schema: Option<MetadataSchema>,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_metadata_schema",
+                manifest_args!(name.to_string(), schema),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn set_metadata_batch(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        entries: Vec<(String, MetadataValue)>,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "set_metadata_batch",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        entries
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn remove_metadata_batch(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        names: Vec<String>,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "remove_metadata_batch",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        names
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn set_node_warden_metadata(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        target: NodeWardenMetadataTarget,
+        name: &str,
+        metadata: MetadataValue,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "set_node_warden_metadata",
+                manifest_args!(target, name.to_string(), metadata),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn lock_node_warden_metadata(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        target: NodeWardenMetadataTarget,
+        name: &str,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "lock_node_warden_metadata",
+                manifest_args!(target, name.to_string()),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_access_key_badge_label(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        owner_badge_global_id: NonFungibleGlobalId,
+        label: AccessKeyLabel,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_access_key_badge_label",
+                manifest_args!(access_key_global_id.local_id(), label),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_access_key_badge_issuance_quota(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        owner_badge_global_id: NonFungibleGlobalId,
+        quota: u32,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_access_key_badge_issuance_quota",
+                manifest_args!(access_key_global_id.local_id(), quota),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn issue_access_key_badge(
+        &mut self,
+        component_address: ComponentAddress,
+        issuer_account: &Account,
+        issuer_access_key_global_id: NonFungibleGlobalId,
+        recipient_account: &Account,
+        permissions: AccessKeyPermissions,
+        validator_scope: ValidatorScope,
+    ) -> NonFungibleGlobalId {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                issuer_account.account_address,
+                issuer_access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "issue_access_key_badge",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        permissions,
+                        validator_scope,
+                        access_key_label(recipient_account),
+                        recipient_account.account_address
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &issuer_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        let local_id: NonFungibleLocalId =
+            receipt.expect_commit_success().output(3);
+        NonFungibleGlobalId::new(
+            issuer_access_key_global_id.resource_address(),
+            local_id,
+        )
+    }
+
+    fn recall_and_burn_issued_access_key_badge(
+        &mut self,
+        component_address: ComponentAddress,
+        issuer_account: &Account,
+        issuer_access_key_global_id: NonFungibleGlobalId,
+        holder_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+    ) {
+        let holder_vault: InternalAddress = InternalAddress::try_from_hex(
+            &self.runner.get_component_vaults(
+                holder_account.account_address,
+                access_key_global_id.resource_address(),
+            )[0]
+            .to_hex(),
+        )
+        .unwrap();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                issuer_account.account_address,
+                issuer_access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "recall_and_burn_issued_access_key_badge",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        holder_vault,
+                        access_key_global_id.local_id()
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &issuer_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn renounce_access_key_badge_permissions(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        permissions: Vec<String>,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "renounce_access_key_badge_permissions",
+                |lookup| manifest_args!(lookup.proof("proof"), permissions),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn report_access_key_badge_compromised(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "report_access_key_badge_compromised",
+                |lookup| manifest_args!(lookup.proof("proof")),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn request_access_key_badge_move(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        target_account: &Account,
+    ) {
+        let admin_vault: InternalAddress = InternalAddress::try_from_hex(
+            &self.runner.get_component_vaults(
+                admin_account.account_address,
+                access_key_global_id.resource_address(),
+            )[0]
+            .to_hex(),
+        )
+        .unwrap();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "request_access_key_badge_move",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        admin_vault,
+                        target_account.account_address
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn approve_access_key_badge_move(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        owner_badge_global_id: NonFungibleGlobalId,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "approve_access_key_badge_move",
+                manifest_args!(access_key_global_id.local_id()),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn update_access_key_badge_required_signer(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        owner_badge_global_id: NonFungibleGlobalId,
+        required_signer: Option<PublicKey>,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "update_access_key_badge_required_signer",
+                manifest_args!(
+                    access_key_global_id.local_id(),
+                    required_signer
+                ),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn rekey_access_key_badge(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        holder_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        new_account: &Account,
+    ) -> NonFungibleGlobalId {
+        let holder_vault: InternalAddress = InternalAddress::try_from_hex(
+            &self.runner.get_component_vaults(
+                holder_account.account_address,
+                access_key_global_id.resource_address(),
+            )[0]
+            .to_hex(),
+        )
+        .unwrap();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "rekey_access_key_badge",
+                manifest_args!(
+                    holder_vault,
+                    access_key_global_id.local_id(),
+                    new_account.account_address
+                ),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        let local_id: NonFungibleLocalId =
+            receipt.expect_commit_success().output(2);
+        NonFungibleGlobalId::new(
+            access_key_global_id.resource_address(),
+            local_id,
+        )
+    }
+
+    fn call_view_method<T: ScryptoDecode>(
+        &mut self,
+        component_address: ComponentAddress,
+        method_name: &str,
+        args: ManifestArgs,
+    ) -> T {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, method_name, args)
+            .build();
+
+        let receipt = self.runner.execute_manifest(manifest, vec![]);
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success().output(1)
+    }
+
+    fn update_fee_with_access_keys(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_resource: ResourceAddress,
+        access_key_local_ids: Vec<NonFungibleLocalId>,
+        validator_address: ComponentAddress,
+        fee: Decimal,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                admin_account.account_address,
+                access_key_resource,
+                access_key_local_ids,
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "update_fee",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        fee
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn call_validator_method_with_access_keys<A: ManifestEncode>(
+        &mut self,
+        component_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_resource: ResourceAddress,
+        access_key_local_ids: Vec<NonFungibleLocalId>,
+        validator_address: ComponentAddress,
+        method_name: &str,
+        args: A,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                admin_account.account_address,
+                access_key_resource,
+                access_key_local_ids,
+            )
+            .pop_from_auth_zone("proof")
+            .call_method_with_name_lookup(
+                component_address,
+                "call_validator_method",
+                |lookup| {
+                    manifest_args!(
+                        lookup.proof("proof"),
+                        validator_address,
+                        method_name.to_string(),
+                        args
+                    )
+                },
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn create_validator_proxy(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        validator_address: ComponentAddress,
+        access_key_badge: Option<NonFungibleGlobalId>,
+    ) -> ComponentAddress {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "create_validator_proxy",
+                manifest_args!(validator_address, access_key_badge),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success().new_component_addresses()[0]
+    }
+
+    fn update_fee_through_validator_proxy(
+        &mut self,
+        validator_proxy_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+        fee: Decimal,
+    ) {
+        // The proof stays in the auth zone, where the proxy checks it
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .call_method(
+                validator_proxy_address,
+                "update_fee",
+                manifest_args!(fee),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn finish_unlock_through_validator_proxy(
+        &mut self,
+        validator_proxy_address: ComponentAddress,
+        admin_account: &Account,
+        access_key_global_id: NonFungibleGlobalId,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                admin_account.account_address,
+                access_key_global_id.clone(),
+            )
+            .call_method(
+                validator_proxy_address,
+                "finish_unlock_owner_stake_units",
+                manifest_args!(),
+            )
+            .deposit_entire_worktop(admin_account.account_address)
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &admin_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+
+    fn migrate(
+        &mut self,
+        old_node_warden: &NodeWardenInstantiateResult,
+        new_node_warden: &NodeWardenInstantiateResult,
+        owner_account: &Account,
+        access_key_badge_local_ids: Vec<NonFungibleLocalId>,
+        mode: MigrationMode,
+        accept: bool,
+    ) -> Vec<NonFungibleLocalId> {
+        let mut manifest_builder = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                old_node_warden.owner_badge.clone(),
+            )
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                new_node_warden.owner_badge.clone(),
+            );
+        if accept {
+            manifest_builder = manifest_builder.call_method(
+                new_node_warden.component_address,
+                "accept_migration",
+                manifest_args!(old_node_warden.component_address),
+            );
+        }
+        let manifest = manifest_builder
+            .call_method(
+                old_node_warden.component_address,
+                "migrate",
+                manifest_args!(
+                    new_node_warden.component_address,
+                    access_key_badge_local_ids,
+                    mode
+                ),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+
+        // The local IDs of the imported access key badges
+        receipt
+            .expect_commit_success()
+            .vault_balance_changes()
+            .values()
+            .filter(|(resource_address, _)| {
+                *resource_address == new_node_warden.access_key_resource
+            })
+            .flat_map(|(_, balance_change)| {
+                balance_change.clone().added_non_fungibles().clone()
+            })
+            .collect()
+    }
+
+    fn new_node_warden_from_snapshot(
+        &mut self,
+        account: &Account,
+        snapshot: NodeWardenSnapshot,
+    ) -> NodeWardenInstantiateResult {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                self.package_address,
+                "NodeWarden",
+                "new_from_snapshot",
+                manifest_args!(
+                    None::<ComponentAddress>,
+                    snapshot,
+                    account.account_address
+                ),
+            )
+            .deposit_entire_worktop(account.account_address)
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&account.public_key)],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        let commit_success = receipt.expect_commit_success();
+
+        let owner_badge_resource = commit_success.new_resource_addresses()[0];
+        let owner_badge_local_id = commit_success
+            .vault_balance_changes()
+            .values()
+            .filter(|(resource_address, _)| {
+                *resource_address == owner_badge_resource
+            })
+            .flat_map(|(_, balance_change)| {
+                balance_change.clone().added_non_fungibles().clone()
+            })
+            .next()
+            .unwrap();
+
+        NodeWardenInstantiateResult {
+            component_address: commit_success.new_component_addresses()[0],
+            owner_badge: NonFungibleGlobalId::new(
+                owner_badge_resource,
+                owner_badge_local_id,
+            ),
+            access_key_resource: commit_success.new_resource_addresses()[1],
+        }
+    }
+
+    fn recall_and_burn_access_key_badge(
+        &mut self,
+        component_address: ComponentAddress,
+        owner_account: &Account,
+        admin_account: &Account,
+        owner_badge_global_id: NonFungibleGlobalId,
+        access_key_global_id: NonFungibleGlobalId,
+    ) {
+        // find the vault address of access_key_global_id
+        let admin_vault: InternalAddress = InternalAddress::try_from_hex(
+            &self.runner.get_component_vaults(
+                admin_account.account_address,
+                access_key_global_id.resource_address(),
+            )[0]
+            .to_hex(),
+        )
+        .unwrap();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungible(
+                owner_account.account_address,
+                owner_badge_global_id.clone(),
+            )
+            .call_method(
+                component_address,
+                "recall_access_key_badge",
+                manifest_args!(admin_vault, access_key_global_id.local_id()),
+            )
+            .take_all_from_worktop(
+                access_key_global_id.resource_address(),
+                "access_key",
+            )
+            .call_method_with_name_lookup(
+                component_address,
+                "burn_access_key_badge",
+                |lookup| manifest_args!(lookup.bucket("access_key")),
+            )
+            .build();
+
+        let receipt = self.runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &owner_account.public_key,
+            )],
+        );
+
+        println!("Transaction Receipt: {:?}", receipt);
+        receipt.expect_commit_success();
+    }
+}
+
+fn access_key_label(account: &Account) -> AccessKeyLabel {
+    AccessKeyLabel {
+        name: "Operator key".to_string(),
+        description: "Access key of an operator".to_string(),
+        key_image_url: Url::of("https://example.com/key.png"),
+        issued_to: Some(account.account_address),
+    }
+}
+
+struct SimpleSetupStuff {
+    env: CustomTestEnvironment,
+    validator_component: ComponentAddress,
+    validator_owner: Account,
+    admin1: Account,
+    node_warden_results: NodeWardenInstantiateResult,
+    admin1_access_key: NonFungibleGlobalId,
+}
+
+fn simple_setup(permissions: AccessKeyPermissions) -> SimpleSetupStuff {
+    let mut env = CustomTestEnvironment::new();
+    let validator_owner = env.accounts[0].clone();
+    let (validator_component, owner_badge_nft_id) =
+        env.create_validator_component(&validator_owner).unwrap();
+
+    let node_warden_results = env.instantiate_node_warden(&validator_owner);
+
+    let admin1 = env.accounts[1].clone();
+
+    let access_key_global_id = env
+        .create_access_key_badge(
+            node_warden_results.component_address.clone(),
+            node_warden_results.owner_badge.clone(),
+            &validator_owner,
+            &admin1,
+            permissions,
+            ValidatorScope::All,
+            node_warden_results.access_key_resource.clone(),
+        )
+        .unwrap();
+
+    env.deposit_validator_owner_badge(
+        node_warden_results.component_address.clone(),
+        &validator_owner,
+        owner_badge_nft_id,
+        node_warden_results.owner_badge.clone(),
+    );
+    return SimpleSetupStuff {
+        env,
+        validator_owner,
+        validator_component,
+        admin1,
+        node_warden_results,
+        admin1_access_key: access_key_global_id,
+    };
+}
+
+#[test]
+fn create_env() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(0.069),
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info
+            .validator_fee_change_request
+            .unwrap()
+            .new_fee_factor
+            == dec!(0.069)
+    );
+}
+
+#[test]
+fn successfully_update_fee() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(0.069),
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info
+            .validator_fee_change_request
+            .unwrap()
+            .new_fee_factor
+            == dec!(0.069)
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Access key badge does not have permission for: unregister"
+)]
+fn unsuccessfully_unregister() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        unregister: false, // not allowed to unregister
+        ..Default::default()
+    });
+
+    env.unregister(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+    );
+}
+
+#[test]
+fn update_fee_then_withdraw_validator_owner_badge() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        unregister: false, // not allowed to unregister
+        ..Default::default()
+    });
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(0.069),
+    );
+
+    env.withdraw_validator_owner_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Expected success but was failure: Failure(SystemModuleError(AuthError(Unauthorized(Unauthorized { failed_access_rules"
+)]
+fn operator_unsuccessfully_withdraw_validator_owner_badge() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        unregister: false, // not allowed to unregister
+        ..Default::default()
+    });
+
+    env.withdraw_validator_owner_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+    );
+}
+
+#[test]
+fn enable_unregister_and_successfully_do_it() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        unregister: false, // don't allowed unregister AT FIRST
+        register: false,   // don't allowed register AT FIRST
+        ..Default::default()
+    });
+
+    env.update_access_key_badge_permissions(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        "unregister",
+        true,
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert!(
+        non_fungible_data.permissions.unregister,
+        "Unregister permission should now be true"
+    );
+
+    env.unregister(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+
+    assert!(
+        !validator_info.is_registered,
+        "Validator should not be registered after unregistering"
+    );
+
+    // now enable the register permission
+
+    env.update_access_key_badge_permissions(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        "register",
+        true,
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert!(
+        non_fungible_data.permissions.register,
+        "Register permission should now be true"
+    );
+
+    env.register(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+
+    assert!(
+        validator_info.is_registered,
+        "Validator should be registered after registering"
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Access key badge does not have permission for: set_metadata"
+)]
+fn unsuccessfully_update_metadata() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: false,
+        ..Default::default()
+    });
+
+    let metadata_value =
+        MetadataValue::String("My validator component".to_string());
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        "name",
+        metadata_value.clone(),
+    );
+}
+
+#[test]
+fn update_metadata() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        ..Default::default()
+    });
+
+    let metadata_value =
+        MetadataValue::String("My validator component".to_string());
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        "name",
+        metadata_value.clone(),
+    );
+
+    let name = env
+        .runner
+        .get_metadata(validator_component.into(), "name")
+        .unwrap();
+
+    assert_eq!(name, metadata_value);
+}
+
+#[test]
+#[should_panic(
+    expected = "InvalidPermission: Invalid permission type: non_existent_permission"
+)]
+fn update_non_existent_permission() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    // Try to update a permission that doesn't exist
+    env.update_access_key_badge_permissions(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        "non_existent_permission",
+        true,
+    );
+}
+
+#[test]
+fn recall_and_burn() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions {
+        ..Default::default()
+    });
+
+    let balances = env.runner.get_component_balance(
+        admin1.account_address,
+        admin1_access_key.resource_address(),
+    );
+
+    assert!(
+        balances == Decimal::ONE,
+        "There should be exactly one access key in the admin's account before recalling/burning"
+    );
+
+    env.recall_and_burn_access_key_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        &admin1,
+        node_warden_results.owner_badge.clone(),
+        admin1_access_key.clone(),
+    );
+
+    let balances = env.runner.get_component_balance(
+        admin1.account_address,
+        admin1_access_key.resource_address(),
+    );
+
+    assert!(
+        balances == Decimal::ZERO,
+        "There should be exactly zero access keys in the admin's account after recalling/burning"
+    );
+}
+
+#[test]
+fn manage_two_validators() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    // Deposit the owner badge of a second validator into the same component
+    let (second_validator_component, second_owner_badge_nft_id) =
+        env.create_validator_component(&validator_owner).unwrap();
+    env.deposit_validator_owner_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        second_owner_badge_nft_id,
+        node_warden_results.owner_badge.clone(),
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        second_validator_component,
+        dec!(0.042),
+    );
+
+    let validator_info =
+        env.runner.get_validator_info(second_validator_component);
+    assert!(
+        validator_info
+            .validator_fee_change_request
+            .unwrap()
+            .new_fee_factor
+            == dec!(0.042)
+    );
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info.validator_fee_change_request.is_none(),
+        "The first validator should not be affected"
+    );
+
+    // Withdrawing one badge leaves the other validator manageable
+    env.withdraw_validator_owner_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        second_validator_component,
+    );
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Access key badge is not scoped to validator")]
+fn scoped_access_key_cannot_touch_other_validator() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key: _,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        ..Default::default()
+    });
+
+    let (second_validator_component, second_owner_badge_nft_id) =
+        env.create_validator_component(&validator_owner).unwrap();
+    env.deposit_validator_owner_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        second_owner_badge_nft_id,
+        node_warden_results.owner_badge.clone(),
+    );
+
+    // This access key only applies to the first validator
+    let scoped_access_key = env
+        .create_access_key_badge(
+            node_warden_results.component_address,
+            node_warden_results.owner_badge.clone(),
+            &validator_owner,
+            &admin1,
+            AccessKeyPermissions {
+                update_fee: true,
+                ..Default::default()
+            },
+            ValidatorScope::Validators(indexset!(validator_component)),
+            node_warden_results.access_key_resource,
+        )
+        .unwrap();
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        scoped_access_key.clone(),
+        validator_component,
+        dec!(0.069),
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        scoped_access_key,
+        second_validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn lock_custodied_owner_stake_units() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        lock_owner_stake_units: true,
+        ..Default::default()
+    });
+
+    let stake_unit_resource = env
+        .runner
+        .get_validator_info(validator_component)
+        .stake_unit_resource;
+
+    // The owner stakes some XRD, and hands the stake units to NodeWarden
+    env.stake_as_owner(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        stake_unit_resource,
+        dec!(1000),
+    );
+
+    // The delegate locks part of them, without ever holding them
+    env.lock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        dec!(400),
+    );
+
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            stake_unit_resource,
+        ),
+        dec!(600),
+        "The remaining stake units should still be in custody"
+    );
+    assert_eq!(
+        env.runner
+            .get_component_balance(admin1.account_address, stake_unit_resource),
+        Decimal::ZERO,
+        "The delegate should never have held any stake units"
+    );
+}
+
+#[test]
+fn stake_from_treasury_within_allowance() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        stake_from_treasury: true,
+        ..Default::default()
+    });
+
+    env.fund_treasury(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        dec!(1000),
+        admin1_access_key.clone(),
+        dec!(500),
+    );
+
+    env.stake_from_treasury(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(300),
+        false,
+    );
+
+    assert_eq!(
+        env.runner
+            .get_component_balance(node_warden_results.component_address, XRD,),
+        dec!(700),
+        "The staked XRD should have left the treasury"
+    );
+    let stake_unit_resource = env
+        .runner
+        .get_validator_info(validator_component)
+        .stake_unit_resource;
+    assert!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            stake_unit_resource,
+        ) > Decimal::ZERO,
+        "The resulting stake units should be held in custody"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Access key badge treasury allowance exceeded")]
+fn stake_from_treasury_exceeding_allowance() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        stake_from_treasury: true,
+        ..Default::default()
+    });
+
+    env.fund_treasury(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        dec!(1000),
+        admin1_access_key.clone(),
+        dec!(500),
+    );
+
+    env.stake_from_treasury(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(300),
+        true,
+    );
+
+    // Only 200 XRD of allowance is left at this point
+    env.stake_from_treasury(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        dec!(300),
+        true,
+    );
+}
+
+#[test]
+fn compound_owner_rewards_relocks_finished_unlocks() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        lock_owner_stake_units: true,
+        start_unlock_owner_stake_units: true,
+        compound_owner_rewards: true,
+        ..Default::default()
+    });
+
+    let stake_unit_resource = env
+        .runner
+        .get_validator_info(validator_component)
+        .stake_unit_resource;
+
+    env.stake_as_owner(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        stake_unit_resource,
+        dec!(1000),
+    );
+    env.lock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.start_unlock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+
+    env.update_compound_policy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        CompoundPolicy {
+            restake: false,
+            relock: true,
+        },
+    );
+
+    // Move past the unlock delay, so the unlock is ready to be finished
+    let current_epoch = env.runner.get_current_epoch();
+    env.runner
+        .set_current_epoch(current_epoch.after(100_000).unwrap());
+
+    env.compound_owner_rewards(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info
+            .pending_owner_stake_unit_withdrawals
+            .is_empty(),
+        "The finished unlock should be gone"
+    );
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            stake_unit_resource,
+        ),
+        Decimal::ZERO,
+        "All unlocked stake units should have been locked again"
+    );
+}
+
+#[test]
+fn compound_owner_rewards_restakes_over_two_cycles() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        lock_owner_stake_units: true,
+        start_unlock_owner_stake_units: true,
+        compound_owner_rewards: true,
+        ..Default::default()
+    });
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    let stake_unit_resource = validator_info.stake_unit_resource;
+    let claim_nft_resource = validator_info.claim_nft;
+
+    env.stake_as_owner(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        stake_unit_resource,
+        dec!(1000),
+    );
+    env.lock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.start_unlock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+
+    env.update_compound_policy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        CompoundPolicy {
+            restake: true,
+            relock: true,
+        },
+    );
+
+    // First cycle: the finished unlock is unstaked into a claim NFT
+    let current_epoch = env.runner.get_current_epoch();
+    env.runner
+        .set_current_epoch(current_epoch.after(100_000).unwrap());
+
+    let first_cycle = env.compound_owner_rewards(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+    );
+
+    assert_eq!(first_cycle.validator_address, validator_component);
+    assert_eq!(first_cycle.unlocked_stake_units, dec!(1000));
+    assert_eq!(first_cycle.unstaked_stake_units, dec!(1000));
+    assert_eq!(first_cycle.restaked_xrd, Decimal::ZERO);
+    assert_eq!(first_cycle.restaked_stake_units, Decimal::ZERO);
+    assert_eq!(first_cycle.relocked_stake_units, Decimal::ZERO);
+    assert_eq!(first_cycle.custodied_stake_units, Decimal::ZERO);
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            claim_nft_resource,
+        ),
+        Decimal::ONE,
+        "The claim NFT should be held in custody"
+    );
+
+    // Second cycle: the claim NFT is ready, and its XRD is staked and locked again
+    let current_epoch = env.runner.get_current_epoch();
+    env.runner
+        .set_current_epoch(current_epoch.after(100_000).unwrap());
+
+    let second_cycle = env.compound_owner_rewards(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+    );
+
+    assert_eq!(second_cycle.validator_address, validator_component);
+    assert_eq!(second_cycle.unlocked_stake_units, Decimal::ZERO);
+    assert_eq!(second_cycle.unstaked_stake_units, Decimal::ZERO);
+    assert_eq!(second_cycle.restaked_xrd, dec!(1000));
+    assert!(second_cycle.restaked_stake_units > Decimal::ZERO);
+    assert_eq!(
+        second_cycle.relocked_stake_units,
+        second_cycle.restaked_stake_units
+    );
+    assert_eq!(second_cycle.custodied_stake_units, Decimal::ZERO);
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            claim_nft_resource,
+        ),
+        Decimal::ZERO,
+        "The claim NFT should have been claimed"
+    );
+}
+
+#[test]
+fn unstake_and_claim_custodied_stake_units() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        unstake: true,
+        claim_xrd: true,
+        ..Default::default()
+    });
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    let stake_unit_resource = validator_info.stake_unit_resource;
+    let claim_nft_resource = validator_info.claim_nft;
+
+    env.stake_as_owner(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        stake_unit_resource,
+        dec!(1000),
+    );
+
+    env.unstake(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            claim_nft_resource,
+        ),
+        Decimal::ONE,
+        "The claim NFT should be held in custody"
+    );
+
+    // Move past the unstake delay, so the claim NFT is ready to be claimed
+    let current_epoch = env.runner.get_current_epoch();
+    env.runner
+        .set_current_epoch(current_epoch.after(100_000).unwrap());
+
+    env.claim_xrd(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+    );
+
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            claim_nft_resource,
+        ),
+        Decimal::ZERO,
+        "The claim NFT should have been claimed"
+    );
+    assert_eq!(
+        env.runner
+            .get_component_balance(node_warden_results.component_address, XRD,),
+        dec!(1000),
+        "The claimed XRD should be in the treasury"
+    );
+    assert_eq!(
+        env.runner
+            .get_component_balance(admin1.account_address, XRD),
+        Decimal::ZERO,
+        "The delegate should never have held the XRD"
+    );
+}
+
+#[test]
+fn compound_owner_rewards_leaves_unstaked_claim_nfts_alone() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        lock_owner_stake_units: true,
+        start_unlock_owner_stake_units: true,
+        compound_owner_rewards: true,
+        unstake: true,
+        claim_xrd: true,
+        ..Default::default()
+    });
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    let stake_unit_resource = validator_info.stake_unit_resource;
+    let claim_nft_resource = validator_info.claim_nft;
+
+    env.stake_as_owner(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        dec!(2000),
+    );
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        stake_unit_resource,
+        dec!(2000),
+    );
+    env.lock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.start_unlock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.unstake(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
+    );
+
+    env.update_compound_policy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        CompoundPolicy {
+            restake: true,
+            relock: true,
+        },
+    );
+
+    // Move past the unlock and unstake delays
+    let current_epoch = env.runner.get_current_epoch();
+    env.runner
+        .set_current_epoch(current_epoch.after(100_000).unwrap());
+
+    env.compound_owner_rewards(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+    );
+
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            claim_nft_resource,
+        ),
+        dec!(2),
+        "The claim NFT of the unstake should not have been restaked"
+    );
+
+    env.claim_xrd(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+    );
+
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            claim_nft_resource,
+        ),
+        Decimal::ONE,
+        "Only the claim NFT of the compound cycle should be left"
+    );
+    assert_eq!(
+        env.runner
+            .get_component_balance(node_warden_results.component_address, XRD),
+        dec!(1000),
+        "The unstaked XRD should be in the treasury"
+    );
+}
+
+#[test]
+fn call_allowlisted_validator_method() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        call_validator_method: true,
+        ..Default::default()
+    });
+
+    env.update_access_key_badge_validator_methods(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        "update_fee",
+        true,
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert!(non_fungible_data
+        .allowed_validator_methods
+        .contains("update_fee"));
+
+    env.call_validator_update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "update_fee",
+        dec!(0.069),
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info
+            .validator_fee_change_request
+            .unwrap()
+            .new_fee_factor
+            == dec!(0.069)
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Access key badge is not allowed to call validator method: update_fee"
+)]
+fn call_validator_method_not_on_allowlist() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        call_validator_method: true,
+        ..Default::default()
+    });
+
+    env.call_validator_update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "update_fee",
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn call_allowlisted_component_method() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        call_component_method: true,
+        ..Default::default()
+    });
+
+    // Any component that requires the validator owner badge will do here,
+    // so we simply use the validator itself.
+    let component_method = ComponentMethod {
+        component_address: validator_component,
+        method_name: "update_fee".to_string(),
+    };
+    env.allow_component_method(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        component_method.clone(),
+    );
+
+    env.call_component_method(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        component_method,
+        dec!(0.069),
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info
+            .validator_fee_change_request
+            .unwrap()
             .new_fee_factor
             == dec!(0.069)
     );
 }
 
 #[test]
-#[should_panic(
-    expected = "Access key badge does not have permission for: unregister"
-)]
-fn unsuccessfully_unregister() {
+#[should_panic(
+    expected = "Access key badge is not allowed to call component method: update_fee"
+)]
+fn call_component_method_not_on_allowlist() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        call_component_method: true,
+        ..Default::default()
+    });
+
+    env.call_component_method(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        ComponentMethod {
+            component_address: validator_component,
+            method_name: "update_fee".to_string(),
+        },
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn delegate_metadata_setter_role() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_role: true,
+        ..Default::default()
+    });
+
+    env.update_delegable_role(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        ValidatorRole::Metadata("metadata_setter".to_string()),
+        true,
+    );
+
+    env.set_validator_role(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        ValidatorRole::Metadata("metadata_setter".to_string()),
+        rule!(require(NonFungibleGlobalId::from_public_key(
+            &admin1.public_key
+        ))),
+    );
+
+    env.set_validator_metadata_directly(
+        &admin1,
+        validator_component,
+        "name",
+        "Delegated",
+    );
+
+    let name = env
+        .runner
+        .get_metadata(validator_component.into(), "name")
+        .unwrap();
+    assert!(name == MetadataValue::String("Delegated".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "This role of the validator is not delegable")]
+fn set_non_delegable_validator_role() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_role: true,
+        ..Default::default()
+    });
+
+    env.set_validator_role(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        ValidatorRole::Owner,
+        rule!(allow_all),
+    );
+}
+
+fn info_url_schema() -> MetadataSchema {
+    MetadataSchema {
+        value_type: MetadataValueType::Url,
+        max_length: Some(64),
+        max_array_size: None,
+        allowed_origins: vec!["https://example.com".to_string()],
+    }
+}
+
+#[test]
+fn set_metadata_following_schema() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        ..Default::default()
+    });
+
+    env.update_metadata_schema(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        "info_url",
+        Some(info_url_schema()),
+    );
+
+    let metadata_value =
+        MetadataValue::Url(UncheckedUrl::of("https://example.com/validator"));
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "info_url",
+        metadata_value.clone(),
+    );
+
+    let info_url = env
+        .runner
+        .get_metadata(validator_component.into(), "info_url")
+        .unwrap();
+    assert_eq!(info_url, metadata_value);
+}
+
+#[test]
+#[should_panic(
+    expected = "Metadata value has the wrong type for field: info_url"
+)]
+fn set_metadata_of_wrong_type() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        ..Default::default()
+    });
+
+    env.update_metadata_schema(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        "info_url",
+        Some(info_url_schema()),
+    );
+
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "info_url",
+        MetadataValue::String("https://example.com".to_string()),
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Metadata value has an origin that is not allowed for field: info_url"
+)]
+fn set_metadata_with_disallowed_origin() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        ..Default::default()
+    });
+
+    env.update_metadata_schema(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        "info_url",
+        Some(info_url_schema()),
+    );
+
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "info_url",
+        MetadataValue::Url(UncheckedUrl::of("https://example.com.evil.io")),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Metadata value is too long for field: info_url")]
+fn set_metadata_longer_than_schema_allows() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        ..Default::default()
+    });
+
+    env.update_metadata_schema(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        "info_url",
+        Some(info_url_schema()),
+    );
+
+    // 20 bytes of origin and path prefix, and 50 more: over the 64 bytes allowed
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "info_url",
+        MetadataValue::Url(UncheckedUrl::of(format!(
+            "https://example.com/{}",
+            "a".repeat(50)
+        ))),
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Metadata value has too many elements for field: tags"
+)]
+fn set_metadata_with_more_elements_than_schema_allows() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        ..Default::default()
+    });
+
+    env.update_metadata_schema(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        "tags",
+        Some(MetadataSchema {
+            value_type: MetadataValueType::StringArray,
+            max_length: None,
+            max_array_size: Some(2),
+            allowed_origins: vec![],
+        }),
+    );
+
+    env.set_metadata(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        "tags",
+        MetadataValue::StringArray(vec![
+            "staking".to_string(),
+            "radix".to_string(),
+            "validator".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn set_and_remove_metadata_batch() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
+        remove_metadata: true,
+        ..Default::default()
+    });
+
+    let name = MetadataValue::String("My validator component".to_string());
+    let description =
+        MetadataValue::String("Run by NodeWarden delegates".to_string());
+    env.set_metadata_batch(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        vec![
+            ("name".to_string(), name.clone()),
+            ("description".to_string(), description.clone()),
+        ],
+    );
+
+    assert_eq!(
+        env.runner
+            .get_metadata(validator_component.into(), "name")
+            .unwrap(),
+        name
+    );
+    assert_eq!(
+        env.runner
+            .get_metadata(validator_component.into(), "description")
+            .unwrap(),
+        description
+    );
+
+    env.remove_metadata_batch(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        vec!["name".to_string(), "description".to_string()],
+    );
+
+    assert!(env
+        .runner
+        .get_metadata(validator_component.into(), "name")
+        .is_none());
+    assert!(env
+        .runner
+        .get_metadata(validator_component.into(), "description")
+        .is_none());
+}
+
+#[test]
+fn set_node_warden_and_badge_metadata() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key: _,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions::default());
+
+    let info_url =
+        MetadataValue::Url(UncheckedUrl::of("https://example.com/warden"));
+    env.set_node_warden_metadata(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        NodeWardenMetadataTarget::Component,
+        "info_url",
+        info_url.clone(),
+    );
+    assert_eq!(
+        env.runner
+            .get_metadata(
+                node_warden_results.component_address.into(),
+                "info_url"
+            )
+            .unwrap(),
+        info_url
+    );
+
+    let icon_url =
+        MetadataValue::Url(UncheckedUrl::of("https://example.com/key.png"));
+    env.set_node_warden_metadata(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        NodeWardenMetadataTarget::AccessKeyBadge,
+        "icon_url",
+        icon_url.clone(),
+    );
+    assert_eq!(
+        env.runner
+            .get_metadata(
+                node_warden_results.access_key_resource.into(),
+                "icon_url"
+            )
+            .unwrap(),
+        icon_url
+    );
+}
+
+#[test]
+fn label_access_key_badge() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions::default());
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert_eq!(non_fungible_data.name, "Operator key");
+    assert_eq!(non_fungible_data.issued_to, Some(admin1.account_address));
+
+    env.update_access_key_badge_label(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        AccessKeyLabel {
+            name: "On-call key".to_string(),
+            description: "Access key of the on-call operator".to_string(),
+            key_image_url: Url::of("https://example.com/on-call.png"),
+            issued_to: None,
+        },
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert_eq!(non_fungible_data.name, "On-call key");
+    assert_eq!(non_fungible_data.issued_to, None);
+}
+
+#[test]
+fn issue_attenuated_access_key_and_recall_it() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        issue_access_keys: true,
+        ..Default::default()
+    });
+    let on_call = env.accounts[2].clone();
+
+    env.update_access_key_badge_issuance_quota(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        1,
+    );
+
+    let on_call_access_key = env.issue_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        &on_call,
+        AccessKeyPermissions {
+            update_fee: true,
+            ..Default::default()
+        },
+        ValidatorScope::Validators(indexset!(validator_component)),
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            on_call_access_key.resource_address(),
+            on_call_access_key.local_id().clone(),
+        );
+    assert_eq!(
+        non_fungible_data.issuer,
+        Some(admin1_access_key.local_id().clone())
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &on_call,
+        on_call_access_key.clone(),
+        validator_component,
+        dec!(0.069),
+    );
+
+    env.recall_and_burn_issued_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        &on_call,
+        on_call_access_key.clone(),
+    );
+
+    let balances = env.runner.get_component_balance(
+        on_call.account_address,
+        on_call_access_key.resource_address(),
+    );
+    assert!(balances == Decimal::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "AssertAccessRuleFailed")]
+fn recall_issued_access_key_without_required_signer() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        issue_access_keys: true,
+        ..Default::default()
+    });
+    let on_call = env.accounts[2].clone();
+    let hardware_key = env.accounts[3].clone();
+
+    env.update_access_key_badge_issuance_quota(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        1,
+    );
+
+    let on_call_access_key = env.issue_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        &on_call,
+        AccessKeyPermissions {
+            update_fee: true,
+            ..Default::default()
+        },
+        ValidatorScope::Validators(indexset!(validator_component)),
+    );
+
+    env.update_access_key_badge_required_signer(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        Some(hardware_key.public_key.into()),
+    );
+
+    env.recall_and_burn_issued_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        &on_call,
+        on_call_access_key,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Access key badge has been reported compromised")]
+fn recall_issued_access_key_after_reporting_compromised() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        issue_access_keys: true,
+        ..Default::default()
+    });
+    let on_call = env.accounts[2].clone();
+
+    env.update_access_key_badge_issuance_quota(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        1,
+    );
+
+    let on_call_access_key = env.issue_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        &on_call,
+        AccessKeyPermissions {
+            update_fee: true,
+            ..Default::default()
+        },
+        ValidatorScope::Validators(indexset!(validator_component)),
+    );
+
+    env.report_access_key_badge_compromised(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+    );
+
+    env.recall_and_burn_issued_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        &on_call,
+        on_call_access_key,
+    );
+}
+
+#[test]
+#[should_panic(expected = "PermissionDenied")]
+fn recall_issued_access_key_without_permission() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        issue_access_keys: true,
+        ..Default::default()
+    });
+    let on_call = env.accounts[2].clone();
+
+    env.update_access_key_badge_issuance_quota(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        1,
+    );
+
+    let on_call_access_key = env.issue_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        &on_call,
+        AccessKeyPermissions {
+            update_fee: true,
+            ..Default::default()
+        },
+        ValidatorScope::Validators(indexset!(validator_component)),
+    );
+
+    env.renounce_access_key_badge_permissions(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        vec!["issue_access_keys".to_string()],
+    );
+
+    env.recall_and_burn_issued_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        &on_call,
+        on_call_access_key,
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Issued access key badge can not have more permissions than its issuer"
+)]
+fn issue_access_key_with_more_permissions() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions {
+        issue_access_keys: true,
+        ..Default::default()
+    });
+    let on_call = env.accounts[2].clone();
+
+    env.update_access_key_badge_issuance_quota(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        1,
+    );
+
+    env.issue_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        &on_call,
+        AccessKeyPermissions {
+            update_fee: true,
+            ..Default::default()
+        },
+        ValidatorScope::All,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Access key badge issuance quota exceeded")]
+fn issue_access_key_without_quota() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions {
+        issue_access_keys: true,
+        ..Default::default()
+    });
+    let on_call = env.accounts[2].clone();
+
+    env.issue_access_key_badge(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        &on_call,
+        AccessKeyPermissions::default(),
+        ValidatorScope::All,
+    );
+}
+
+#[test]
+fn renounce_own_permission() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component: _,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        register: true,
+        ..Default::default()
+    });
+
+    env.renounce_access_key_badge_permissions(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        vec!["update_fee".to_string()],
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert!(!non_fungible_data.permissions.update_fee);
+    assert!(non_fungible_data.permissions.register);
+}
+
+#[test]
+#[should_panic(expected = "Access key badge has been reported compromised")]
+fn use_access_key_after_reporting_compromised() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    env.report_access_key_badge_compromised(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn move_access_key_to_new_account() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+    let new_account = env.accounts[2].clone();
+
+    env.request_access_key_badge_move(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        &new_account,
+    );
+
+    env.approve_access_key_badge_move(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+    );
+
+    assert!(
+        env.runner.get_component_balance(
+            admin1.account_address,
+            admin1_access_key.resource_address(),
+        ) == Decimal::ZERO
+    );
+    assert!(
+        env.runner.get_component_balance(
+            new_account.account_address,
+            admin1_access_key.resource_address(),
+        ) == Decimal::ONE
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            admin1_access_key.resource_address(),
+            admin1_access_key.local_id().clone(),
+        );
+    assert_eq!(
+        non_fungible_data.issued_to,
+        Some(new_account.account_address)
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &new_account,
+        admin1_access_key,
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn use_access_key_signed_by_required_signer() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    // The transactions of admin1 are signed by their own key
+    env.update_access_key_badge_required_signer(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        Some(admin1.public_key.into()),
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+#[should_panic(expected = "AssertAccessRuleFailed")]
+fn use_access_key_without_required_signer() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+    let hardware_key = env.accounts[3].clone();
+
+    env.update_access_key_badge_required_signer(
+        node_warden_results.component_address,
+        &validator_owner,
+        admin1_access_key.clone(),
+        node_warden_results.owner_badge.clone(),
+        Some(hardware_key.public_key.into()),
+    );
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn rekey_access_key_to_new_account() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+    let new_account = env.accounts[2].clone();
+
+    let new_access_key = env.rekey_access_key_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        &admin1,
+        admin1_access_key.clone(),
+        &new_account,
+    );
+
+    assert!(
+        env.runner.get_component_balance(
+            admin1.account_address,
+            admin1_access_key.resource_address(),
+        ) == Decimal::ZERO
+    );
+    assert_eq!(
+        env.call_view_method::<Option<NonFungibleLocalId>>(
+            node_warden_results.component_address,
+            "get_access_key_badge_successor",
+            manifest_args!(admin1_access_key.local_id()),
+        ),
+        Some(new_access_key.local_id().clone())
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            new_access_key.resource_address(),
+            new_access_key.local_id().clone(),
+        );
+    assert!(non_fungible_data.permissions.update_fee);
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &new_account,
+        new_access_key,
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn rekey_access_key_reported_compromised() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+    let new_account = env.accounts[2].clone();
+
+    env.report_access_key_badge_compromised(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+    );
+
+    let new_access_key = env.rekey_access_key_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        &admin1,
+        admin1_access_key,
+        &new_account,
+    );
+
+    let non_fungible_data: AccessKeyBadgeData =
+        env.runner.get_non_fungible_data(
+            new_access_key.resource_address(),
+            new_access_key.local_id().clone(),
+        );
+    assert!(!non_fungible_data.compromised);
+
+    env.update_fee(
+        node_warden_results.component_address,
+        &new_account,
+        new_access_key,
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn query_views() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    let validator_addresses: Vec<ComponentAddress> = env.call_view_method(
+        node_warden_results.component_address,
+        "get_validator_addresses",
+        manifest_args!(),
+    );
+    assert_eq!(validator_addresses, vec![validator_component]);
+
+    let has_validator_owner_badge: bool = env.call_view_method(
+        node_warden_results.component_address,
+        "has_validator_owner_badge",
+        manifest_args!(validator_component),
+    );
+    assert!(has_validator_owner_badge);
+
+    let permissions: AccessKeyPermissions = env.call_view_method(
+        node_warden_results.component_address,
+        "get_access_key_permissions",
+        manifest_args!(admin1_access_key.local_id()),
+    );
+    assert!(permissions.update_fee);
+    assert!(!permissions.register);
+
+    let configuration: NodeWardenConfiguration = env.call_view_method(
+        node_warden_results.component_address,
+        "get_configuration",
+        manifest_args!(),
+    );
+    assert_eq!(
+        configuration.owner_badge_resource,
+        node_warden_results.owner_badge.resource_address()
+    );
+    assert_eq!(
+        configuration.access_key_resource,
+        node_warden_results.access_key_resource
+    );
+    assert!(configuration.validators.contains_key(&validator_component));
+}
+
+#[test]
+fn dry_run_permission_checks() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        call_validator_method: true,
+        ..Default::default()
+    });
+
+    let result: Result<(), NodeWardenError> = env.call_view_method(
+        node_warden_results.component_address,
+        "can",
+        manifest_args!(
+            admin1_access_key.local_id(),
+            validator_component,
+            PermissionType::UpdateFee
+        ),
+    );
+    assert_eq!(result, Ok(()));
+
+    let result: Result<(), NodeWardenError> = env.call_view_method(
+        node_warden_results.component_address,
+        "can",
+        manifest_args!(
+            admin1_access_key.local_id(),
+            validator_component,
+            PermissionType::Register
+        ),
+    );
+    assert_eq!(
+        result,
+        Err(NodeWardenError::PermissionDenied(PermissionType::Register))
+    );
+
+    let result: Result<(), NodeWardenError> = env.call_view_method(
+        node_warden_results.component_address,
+        "can_perform",
+        manifest_args!(
+            admin1_access_key.local_id(),
+            validator_component,
+            ProposedAction::CallValidatorMethod("update_fee".to_string())
+        ),
+    );
+    assert_eq!(
+        result,
+        Err(NodeWardenError::ValidatorMethodNotAllowed(
+            "update_fee".to_string()
+        ))
+    );
+}
+
+#[test]
+fn dry_run_permissions_without_validator_or_call_checks() {
     let SimpleSetupStuff {
         mut env,
         validator_owner: _,
         admin1,
         node_warden_results,
         admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        call_validator_method: true,
+        issue_access_keys: true,
+        ..Default::default()
+    });
+
+    // The allowlist of the access key badge can only be checked for a proposed call
+    let result: Result<(), NodeWardenError> = env.call_view_method(
+        node_warden_results.component_address,
+        "can",
+        manifest_args!(
+            admin1_access_key.local_id(),
+            validator_component,
+            PermissionType::CallValidatorMethod
+        ),
+    );
+    assert_eq!(
+        result,
+        Err(NodeWardenError::ProposedActionRequired(
+            PermissionType::CallValidatorMethod
+        ))
+    );
+
+    // Issuing access keys does not act on a validator
+    let result: Result<(), NodeWardenError> = env.call_view_method(
+        node_warden_results.component_address,
+        "can",
+        manifest_args!(
+            admin1_access_key.local_id(),
+            admin1.account_address,
+            PermissionType::IssueAccessKeys
+        ),
+    );
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn effective_permissions_of_owner_badge_proof_holder() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner: _,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key,
         validator_component: _,
+    } = simple_setup(AccessKeyPermissions {
+        create_validator_owner_badge_proof: true,
+        ..Default::default()
+    });
+
+    let permissions: AccessKeyPermissions = env.call_view_method(
+        node_warden_results.component_address,
+        "get_effective_permissions",
+        manifest_args!(admin1_access_key.local_id()),
+    );
+    assert!(permissions.update_fee);
+    assert!(permissions.set_role);
+    assert!(!permissions.stake_from_treasury);
+    assert!(!permissions.issue_access_keys);
+}
+
+struct TwoScopedAccessKeysStuff {
+    env: CustomTestEnvironment,
+    validator_component: ComponentAddress,
+    second_validator_component: ComponentAddress,
+    admin1: Account,
+    node_warden_results: NodeWardenInstantiateResult,
+    first_access_key: NonFungibleGlobalId,
+    second_access_key: NonFungibleGlobalId,
+}
+
+fn two_scoped_access_keys_setup() -> TwoScopedAccessKeysStuff {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key: _,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        ..Default::default()
+    });
+
+    let (second_validator_component, second_owner_badge_nft_id) =
+        env.create_validator_component(&validator_owner).unwrap();
+    env.deposit_validator_owner_badge(
+        node_warden_results.component_address,
+        &validator_owner,
+        second_owner_badge_nft_id,
+        node_warden_results.owner_badge.clone(),
+    );
+
+    // Each access key only applies to one of the validators
+    let mut scoped_access_key = |validator_address: ComponentAddress| {
+        env.create_access_key_badge(
+            node_warden_results.component_address,
+            node_warden_results.owner_badge.clone(),
+            &validator_owner,
+            &admin1,
+            AccessKeyPermissions {
+                update_fee: true,
+                ..Default::default()
+            },
+            ValidatorScope::Validators(indexset!(validator_address)),
+            node_warden_results.access_key_resource,
+        )
+        .unwrap()
+    };
+    let first_access_key = scoped_access_key(validator_component);
+    let second_access_key = scoped_access_key(second_validator_component);
+
+    TwoScopedAccessKeysStuff {
+        env,
+        validator_component,
+        second_validator_component,
+        admin1,
+        node_warden_results,
+        first_access_key,
+        second_access_key,
+    }
+}
+
+#[test]
+fn combine_scoped_access_keys_in_one_proof() {
+    let TwoScopedAccessKeysStuff {
+        mut env,
+        validator_component,
+        second_validator_component,
+        admin1,
+        node_warden_results,
+        first_access_key,
+        second_access_key,
+    } = two_scoped_access_keys_setup();
+
+    // A proof of both access keys covers both validators
+    for validator_address in [validator_component, second_validator_component] {
+        env.update_fee_with_access_keys(
+            node_warden_results.component_address,
+            &admin1,
+            node_warden_results.access_key_resource,
+            vec![
+                first_access_key.local_id().clone(),
+                second_access_key.local_id().clone(),
+            ],
+            validator_address,
+            dec!(0.069),
+        );
+    }
+}
+
+#[test]
+fn combine_access_keys_with_different_allowlists_in_one_proof() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key: _,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions::default());
+
+    // Each access key may only call one of the validator methods
+    let mut allowlisted_access_key = |method_name: &str| {
+        let access_key = env
+            .create_access_key_badge(
+                node_warden_results.component_address,
+                node_warden_results.owner_badge.clone(),
+                &validator_owner,
+                &admin1,
+                AccessKeyPermissions {
+                    call_validator_method: true,
+                    ..Default::default()
+                },
+                ValidatorScope::All,
+                node_warden_results.access_key_resource,
+            )
+            .unwrap();
+        env.update_access_key_badge_validator_methods(
+            node_warden_results.component_address,
+            &validator_owner,
+            access_key.clone(),
+            node_warden_results.owner_badge.clone(),
+            method_name,
+            true,
+        );
+        access_key.local_id().clone()
+    };
+    let update_fee_access_key = allowlisted_access_key("update_fee");
+    let register_access_key = allowlisted_access_key("register");
+
+    // A proof of both access keys may call both methods, whatever the order
+    let access_key_local_ids =
+        vec![update_fee_access_key.clone(), register_access_key.clone()];
+    env.call_validator_method_with_access_keys(
+        node_warden_results.component_address,
+        &admin1,
+        node_warden_results.access_key_resource,
+        access_key_local_ids.clone(),
+        validator_component,
+        "register",
+        (),
+    );
+    env.call_validator_method_with_access_keys(
+        node_warden_results.component_address,
+        &admin1,
+        node_warden_results.access_key_resource,
+        access_key_local_ids,
+        validator_component,
+        "update_fee",
+        (dec!(0.069),),
+    );
+
+    let validator_info = env.runner.get_validator_info(validator_component);
+    assert!(
+        validator_info.is_registered,
+        "The validator should be registered"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Access key badge has been reported compromised")]
+fn combine_access_keys_with_a_compromised_one() {
+    let TwoScopedAccessKeysStuff {
+        mut env,
+        validator_component,
+        second_validator_component: _,
+        admin1,
+        node_warden_results,
+        first_access_key,
+        second_access_key,
+    } = two_scoped_access_keys_setup();
+
+    env.report_access_key_badge_compromised(
+        node_warden_results.component_address,
+        &admin1,
+        second_access_key.clone(),
+    );
+
+    // The first access key grants the action, but the compromised one fails the proof
+    env.update_fee_with_access_keys(
+        node_warden_results.component_address,
+        &admin1,
+        node_warden_results.access_key_resource,
+        vec![
+            first_access_key.local_id().clone(),
+            second_access_key.local_id().clone(),
+        ],
+        validator_component,
+        dec!(0.069),
+    );
+}
+
+#[test]
+fn update_fee_through_validator_proxy() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    let validator_proxy = env.create_validator_proxy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        Some(admin1_access_key.clone()),
+    );
+    let registered_proxy: Option<ComponentAddress> = env.call_view_method(
+        node_warden_results.component_address,
+        "get_validator_proxy",
+        manifest_args!(validator_component, Some(admin1_access_key.clone())),
+    );
+    assert_eq!(registered_proxy, Some(validator_proxy));
+
+    env.update_fee_through_validator_proxy(
+        validator_proxy,
+        &admin1,
+        admin1_access_key,
+        dec!(0.069),
+    );
+}
+
+#[test]
+#[should_panic(expected = "PermissionDenied")]
+fn update_fee_through_validator_proxy_without_permission() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        register: true,
+        ..Default::default()
+    });
+
+    let validator_proxy = env.create_validator_proxy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        Some(admin1_access_key.clone()),
+    );
+
+    env.update_fee_through_validator_proxy(
+        validator_proxy,
+        &admin1,
+        admin1_access_key,
+        dec!(0.069),
+    );
+}
+
+#[test]
+#[should_panic(expected = "AssertAccessRuleFailed")]
+fn update_fee_through_validator_proxy_of_another_access_key() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+
+    // The proxy only accepts the NodeWarden owner badge
+    let validator_proxy = env.create_validator_proxy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        None,
+    );
+
+    env.update_fee_through_validator_proxy(
+        validator_proxy,
+        &admin1,
+        admin1_access_key,
+        dec!(0.069),
+    );
+}
+
+#[test]
+#[should_panic(expected = "AssertAccessRuleFailed")]
+fn create_validator_proxy_proof_directly() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
     } = simple_setup(AccessKeyPermissions {
         update_fee: true,
-        unregister: false, // not allowed to unregister
         ..Default::default()
     });
+    let attacker = env.accounts[3].clone();
+
+    let validator_proxy = env.create_validator_proxy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        Some(admin1_access_key),
+    );
+
+    // Skipping the proxy must not hand out a proof of the validator owner badge
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            node_warden_results.component_address,
+            "create_validator_proxy_proof",
+            manifest_args!(validator_proxy, PermissionType::UpdateFee),
+        )
+        .call_method(
+            validator_component,
+            "update_fee",
+            manifest_args!(dec!(0.069)),
+        )
+        .build();
 
-    env.unregister(
-        node_warden_results.component_address,
-        &admin1,
-        admin1_access_key.clone(),
+    let receipt = env.runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&attacker.public_key)],
     );
+
+    println!("Transaction Receipt: {:?}", receipt);
+    receipt.expect_commit_success();
 }
 
 #[test]
-fn update_fee_then_withdraw_validator_owner_badge() {
+fn finish_unlock_through_validator_proxy_into_custody() {
     let SimpleSetupStuff {
         mut env,
         validator_owner,
         admin1,
         node_warden_results,
         admin1_access_key,
-        validator_component: _,
+        validator_component,
     } = simple_setup(AccessKeyPermissions {
-        update_fee: true,
-        unregister: false, // not allowed to unregister
+        lock_owner_stake_units: true,
+        start_unlock_owner_stake_units: true,
+        finish_unlock_owner_stake_units: true,
         ..Default::default()
     });
 
-    env.update_fee(
+    let stake_unit_resource = env
+        .runner
+        .get_validator_info(validator_component)
+        .stake_unit_resource;
+
+    env.stake_as_owner(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        dec!(1000),
+    );
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        validator_component,
+        stake_unit_resource,
+        dec!(1000),
+    );
+    env.lock_owner_stake_units(
         node_warden_results.component_address,
         &admin1,
         admin1_access_key.clone(),
-        dec!(0.069),
+        validator_component,
+        dec!(1000),
+    );
+    env.start_unlock_owner_stake_units(
+        node_warden_results.component_address,
+        &admin1,
+        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
     );
 
-    env.withdraw_validator_owner_badge(
+    let validator_proxy = env.create_validator_proxy(
         node_warden_results.component_address,
         &validator_owner,
         node_warden_results.owner_badge.clone(),
+        validator_component,
+        Some(admin1_access_key.clone()),
+    );
+
+    // Move past the unlock delay, so the unlock is ready to be finished
+    let current_epoch = env.runner.get_current_epoch();
+    env.runner
+        .set_current_epoch(current_epoch.after(100_000).unwrap());
+
+    env.finish_unlock_through_validator_proxy(
+        validator_proxy,
+        &admin1,
+        admin1_access_key,
+    );
+
+    assert_eq!(
+        env.runner
+            .get_component_balance(admin1.account_address, stake_unit_resource),
+        Decimal::ZERO,
+        "The delegate should never have held any stake units"
+    );
+    assert_eq!(
+        env.runner.get_component_balance(
+            node_warden_results.component_address,
+            stake_unit_resource,
+        ),
+        dec!(1000),
+        "The unlocked stake units should be in custody"
     );
 }
 
 #[test]
-#[should_panic(
-    expected = "Expected success but was failure: Failure(SystemModuleError(AuthError(Unauthorized(Unauthorized { failed_access_rules"
-)]
-fn operator_unsuccessfully_withdraw_validator_owner_badge() {
+fn migrate_honouring_old_access_keys() {
     let SimpleSetupStuff {
         mut env,
-        validator_owner: _,
+        validator_owner,
         admin1,
         node_warden_results,
         admin1_access_key,
-        validator_component: _,
+        validator_component,
     } = simple_setup(AccessKeyPermissions {
         update_fee: true,
-        unregister: false, // not allowed to unregister
         ..Default::default()
     });
+    let new_node_warden_results = env.instantiate_node_warden(&validator_owner);
 
-    env.withdraw_validator_owner_badge(
+    env.migrate(
+        &node_warden_results,
+        &new_node_warden_results,
+        &validator_owner,
+        vec![admin1_access_key.local_id().clone()],
+        MigrationMode::Honour,
+        true,
+    );
+
+    let has_validator_owner_badge: bool = env.call_view_method(
         node_warden_results.component_address,
+        "has_validator_owner_badge",
+        manifest_args!(validator_component),
+    );
+    assert!(!has_validator_owner_badge);
+    let honoured_access_key: Option<NonFungibleLocalId> = env.call_view_method(
+        new_node_warden_results.component_address,
+        "get_honoured_access_key_badge",
+        manifest_args!(admin1_access_key.local_id()),
+    );
+    assert!(honoured_access_key.is_some());
+
+    // The old access key badge keeps working on the new component
+    env.update_fee(
+        new_node_warden_results.component_address,
         &admin1,
         admin1_access_key,
+        validator_component,
+        dec!(0.069),
     );
 }
 
 #[test]
-fn enable_unregister_and_successfully_do_it() {
+#[should_panic(expected = "Access key badge has been reported compromised")]
+fn report_honoured_access_key_compromised() {
     let SimpleSetupStuff {
         mut env,
         validator_owner,
@@ -726,202 +4984,344 @@ fn enable_unregister_and_successfully_do_it() {
         validator_component,
     } = simple_setup(AccessKeyPermissions {
         update_fee: true,
-        unregister: false, // don't allowed unregister AT FIRST
-        register: false,   // don't allowed register AT FIRST
         ..Default::default()
     });
+    let new_node_warden_results = env.instantiate_node_warden(&validator_owner);
 
-    env.update_access_key_badge_permissions(
-        node_warden_results.component_address,
+    env.migrate(
+        &node_warden_results,
+        &new_node_warden_results,
         &validator_owner,
-        admin1_access_key.clone(),
-        node_warden_results.owner_badge.clone(),
-        "unregister",
+        vec![admin1_access_key.local_id().clone()],
+        MigrationMode::Honour,
         true,
     );
 
-    let non_fungible_data: AccessKeyBadgeData =
-        env.runner.get_non_fungible_data(
-            admin1_access_key.resource_address(),
-            admin1_access_key.local_id().clone(),
-        );
-    assert!(
-        non_fungible_data.permissions.unregister,
-        "Unregister permission should now be true"
-    );
-
-    env.unregister(
-        node_warden_results.component_address,
+    env.report_access_key_badge_compromised(
+        new_node_warden_results.component_address,
         &admin1,
         admin1_access_key.clone(),
     );
 
-    let validator_info = env.runner.get_validator_info(validator_component);
-
-    assert!(
-        !validator_info.is_registered,
-        "Validator should not be registered after unregistering"
+    env.update_fee(
+        new_node_warden_results.component_address,
+        &admin1,
+        admin1_access_key,
+        validator_component,
+        dec!(0.069),
     );
+}
 
-    // now enable the register permission
+#[test]
+fn move_honoured_access_key_to_new_account() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1,
+        node_warden_results,
+        admin1_access_key,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions {
+        update_fee: true,
+        ..Default::default()
+    });
+    let new_account = env.accounts[2].clone();
+    let new_node_warden_results = env.instantiate_node_warden(&validator_owner);
 
-    env.update_access_key_badge_permissions(
-        node_warden_results.component_address,
+    env.migrate(
+        &node_warden_results,
+        &new_node_warden_results,
         &validator_owner,
-        admin1_access_key.clone(),
-        node_warden_results.owner_badge.clone(),
-        "register",
+        vec![admin1_access_key.local_id().clone()],
+        MigrationMode::Honour,
         true,
     );
-
-    let non_fungible_data: AccessKeyBadgeData =
-        env.runner.get_non_fungible_data(
-            admin1_access_key.resource_address(),
-            admin1_access_key.local_id().clone(),
-        );
-    assert!(
-        non_fungible_data.permissions.register,
-        "Register permission should now be true"
+    let imported_local_id: Option<NonFungibleLocalId> = env.call_view_method(
+        new_node_warden_results.component_address,
+        "get_honoured_access_key_badge",
+        manifest_args!(admin1_access_key.local_id()),
+    );
+    let imported_access_key = NonFungibleGlobalId::new(
+        new_node_warden_results.access_key_resource,
+        imported_local_id.unwrap(),
     );
 
-    env.register(
-        node_warden_results.component_address,
+    env.request_access_key_badge_move(
+        new_node_warden_results.component_address,
         &admin1,
         admin1_access_key.clone(),
+        &new_account,
+    );
+    env.approve_access_key_badge_move(
+        new_node_warden_results.component_address,
+        &validator_owner,
+        imported_access_key.clone(),
+        new_node_warden_results.owner_badge.clone(),
     );
 
-    let validator_info = env.runner.get_validator_info(validator_component);
+    // The old access key badge no longer stands for the imported one
+    let honoured_access_key: Option<NonFungibleLocalId> = env.call_view_method(
+        new_node_warden_results.component_address,
+        "get_honoured_access_key_badge",
+        manifest_args!(admin1_access_key.local_id()),
+    );
+    assert!(honoured_access_key.is_none());
 
-    assert!(
-        validator_info.is_registered,
-        "Validator should be registered after registering"
+    env.update_fee(
+        new_node_warden_results.component_address,
+        &new_account,
+        imported_access_key,
+        validator_component,
+        dec!(0.069),
     );
 }
 
 #[test]
-#[should_panic(
-    expected = "Access key badge does not have permission for: set_metadata"
-)]
-fn unsuccessfully_update_metadata() {
+fn migrate_reminting_access_keys() {
     let SimpleSetupStuff {
         mut env,
-        validator_owner: _,
+        validator_owner,
         admin1,
         node_warden_results,
         admin1_access_key,
-        validator_component: _,
+        validator_component,
     } = simple_setup(AccessKeyPermissions {
-        set_metadata: false,
+        update_fee: true,
         ..Default::default()
     });
+    let new_node_warden_results = env.instantiate_node_warden(&validator_owner);
 
-    let metadata_value =
-        MetadataValue::String("My validator component".to_string());
-    env.set_metadata(
-        node_warden_results.component_address,
+    let reminted_local_ids = env.migrate(
+        &node_warden_results,
+        &new_node_warden_results,
+        &validator_owner,
+        vec![admin1_access_key.local_id().clone()],
+        MigrationMode::Remint,
+        true,
+    );
+    assert_eq!(reminted_local_ids.len(), 1);
+
+    // The re-minted access key badge was delivered to the holder
+    env.update_fee(
+        new_node_warden_results.component_address,
         &admin1,
-        admin1_access_key.clone(),
-        "name",
-        metadata_value.clone(),
+        NonFungibleGlobalId::new(
+            new_node_warden_results.access_key_resource,
+            reminted_local_ids[0].clone(),
+        ),
+        validator_component,
+        dec!(0.069),
     );
 }
 
 #[test]
-fn update_metadata() {
+#[should_panic(expected = "MigrationNotAccepted")]
+fn migrate_without_acceptance() {
     let SimpleSetupStuff {
         mut env,
-        validator_owner: _,
-        admin1,
+        validator_owner,
+        admin1: _,
         node_warden_results,
         admin1_access_key,
-        validator_component,
+        validator_component: _,
     } = simple_setup(AccessKeyPermissions {
-        set_metadata: true,
+        update_fee: true,
         ..Default::default()
     });
+    let new_node_warden_results = env.instantiate_node_warden(&validator_owner);
 
-    let metadata_value =
-        MetadataValue::String("My validator component".to_string());
-    env.set_metadata(
-        node_warden_results.component_address,
-        &admin1,
-        admin1_access_key.clone(),
-        "name",
-        metadata_value.clone(),
+    env.migrate(
+        &node_warden_results,
+        &new_node_warden_results,
+        &validator_owner,
+        vec![admin1_access_key.local_id().clone()],
+        MigrationMode::Honour,
+        false,
     );
-
-    let name = env
-        .runner
-        .get_metadata(validator_component.into(), "name")
-        .unwrap();
-
-    assert_eq!(name, metadata_value);
 }
 
 #[test]
-#[should_panic(expected = "Invalid permission type: VariantNotFound")]
-fn update_non_existent_permission() {
+fn replicate_node_warden_from_snapshot() {
     let SimpleSetupStuff {
         mut env,
         validator_owner,
         admin1: _,
         node_warden_results,
-        admin1_access_key,
-        validator_component: _,
+        admin1_access_key: _,
+        validator_component,
     } = simple_setup(AccessKeyPermissions {
         update_fee: true,
         ..Default::default()
     });
 
-    // Try to update a permission that doesn't exist
-    env.update_access_key_badge_permissions(
+    let info_url =
+        MetadataValue::Url(UncheckedUrl::of("https://example.com/warden"));
+    env.set_node_warden_metadata(
         node_warden_results.component_address,
         &validator_owner,
-        admin1_access_key.clone(),
         node_warden_results.owner_badge.clone(),
-        "non_existent_permission",
-        true,
+        NodeWardenMetadataTarget::Component,
+        "info_url",
+        info_url.clone(),
+    );
+    env.update_compound_policy(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        CompoundPolicy {
+            restake: true,
+            relock: false,
+        },
+    );
+
+    let snapshot: NodeWardenSnapshot = env.call_view_method(
+        node_warden_results.component_address,
+        "export_configuration",
+        manifest_args!(),
+    );
+    assert_eq!(snapshot.validators, vec![validator_component]);
+    assert_eq!(snapshot.access_key_badges.len(), 1);
+
+    let replica_results =
+        env.new_node_warden_from_snapshot(&validator_owner, snapshot);
+    assert_eq!(
+        env.runner
+            .get_metadata(replica_results.component_address.into(), "info_url")
+            .unwrap(),
+        info_url
+    );
+
+    let replica_snapshot: NodeWardenSnapshot = env.call_view_method(
+        replica_results.component_address,
+        "export_configuration",
+        manifest_args!(),
+    );
+    // Validators are not restored, their owner badges stay where they are
+    assert!(replica_snapshot.validators.is_empty());
+    assert!(replica_snapshot.compound_policy.restake);
+    assert!(!replica_snapshot.compound_policy.relock);
+    assert_eq!(replica_snapshot.access_key_badges.len(), 1);
+    assert!(
+        replica_snapshot.access_key_badges[0]
+            .data
+            .permissions
+            .update_fee
+    );
+    assert_eq!(
+        env.runner.get_component_balance(
+            validator_owner.account_address,
+            replica_results.access_key_resource
+        ),
+        dec!(1)
     );
 }
 
 #[test]
-fn recall_and_burn() {
+fn replicate_metadata_schemas_and_locks_from_snapshot() {
     let SimpleSetupStuff {
         mut env,
         validator_owner,
-        admin1,
+        admin1: _,
         node_warden_results,
-        admin1_access_key,
+        admin1_access_key: _,
         validator_component: _,
     } = simple_setup(AccessKeyPermissions {
+        set_metadata: true,
         ..Default::default()
     });
 
-    let balances = env.runner.get_component_balance(
-        admin1.account_address,
-        admin1_access_key.resource_address(),
+    env.update_metadata_schema(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        "info_url",
+        Some(info_url_schema()),
+    );
+    env.set_node_warden_metadata(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        NodeWardenMetadataTarget::Component,
+        "info_url",
+        MetadataValue::Url(UncheckedUrl::of("https://example.com/warden")),
+    );
+    env.lock_node_warden_metadata(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        NodeWardenMetadataTarget::Component,
+        "info_url",
     );
 
-    assert!(
-        balances == Decimal::ONE,
-        "There should be exactly one access key in the admin's account before recalling/burning"
+    let snapshot: NodeWardenSnapshot = env.call_view_method(
+        node_warden_results.component_address,
+        "export_configuration",
+        manifest_args!(),
     );
+    let replica_results =
+        env.new_node_warden_from_snapshot(&validator_owner, snapshot);
 
-    env.recall_and_burn_access_key_badge(
+    let replica_snapshot: NodeWardenSnapshot = env.call_view_method(
+        replica_results.component_address,
+        "export_configuration",
+        manifest_args!(),
+    );
+    assert_eq!(replica_snapshot.metadata_schemas.len(), 1);
+    let (name, schema) = &replica_snapshot.metadata_schemas[0];
+    assert_eq!(name, "info_url");
+    assert_eq!(schema.value_type, MetadataValueType::Url);
+    assert_eq!(schema.max_length, Some(64));
+    assert_eq!(
+        schema.allowed_origins,
+        vec!["https://example.com".to_string()]
+    );
+    assert_eq!(
+        replica_snapshot.locked_metadata,
+        vec![(NodeWardenMetadataTarget::Component, "info_url".to_string())]
+    );
+}
+
+#[test]
+#[should_panic(expected = "NoStakeUnitResource")]
+fn deposit_owner_stake_units_of_non_validator() {
+    let SimpleSetupStuff {
+        mut env,
+        validator_owner,
+        admin1: _,
+        node_warden_results,
+        admin1_access_key: _,
+        validator_component,
+    } = simple_setup(AccessKeyPermissions::default());
+
+    let stake_unit_resource = env
+        .runner
+        .get_validator_info(validator_component)
+        .stake_unit_resource;
+    env.stake_as_owner(
         node_warden_results.component_address,
         &validator_owner,
-        &admin1,
         node_warden_results.owner_badge.clone(),
-        admin1_access_key.clone(),
+        validator_component,
+        dec!(1000),
     );
 
-    let balances = env.runner.get_component_balance(
-        admin1.account_address,
-        admin1_access_key.resource_address(),
+    // NodeWarden itself is no validator, and has no stake unit resource
+    env.deposit_owner_stake_units(
+        node_warden_results.component_address,
+        &validator_owner,
+        node_warden_results.owner_badge.clone(),
+        node_warden_results.component_address,
+        stake_unit_resource,
+        dec!(1000),
     );
+}
 
-    assert!(
-        balances == Decimal::ZERO,
-        "There should be exactly zero access keys in the admin's account after recalling/burning"
+#[test]
+fn display_errors_off_ledger() {
+    let validator_address = ComponentAddress::new_or_panic(
+        [EntityType::GlobalValidator as u8; NodeId::LENGTH],
     );
+    let error = NodeWardenError::NotScopedToValidator(validator_address);
+    assert!(error.to_string().starts_with("NotScopedToValidator: "));
+    assert!(error.to_string().ends_with(&validator_address.to_hex()));
 }